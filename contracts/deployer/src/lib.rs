@@ -22,7 +22,7 @@ pub struct MultisigDeployer;
 #[contractimpl]
 impl MultisigDeployer {
     #[allow(dead_code)]
-    pub fn initialize(env: Env, multisig_wasm_hash: BytesN<32>) {
+    pub fn initialize(env: Env, admin: Address, multisig_wasm_hash: BytesN<32>) {
         if is_initialized(&env) {
             log!(
                 &env,
@@ -30,12 +30,46 @@ impl MultisigDeployer {
             );
             panic!("Multisig Deployer: Initialize: initializing the contract twice is not allowed");
         }
+        admin.require_auth();
         set_initialized(&env);
 
+        set_admin(&env, &admin);
         set_wasm_hash(&env, &multisig_wasm_hash);
     }
 
+    /// Publishes an audited multisig release. Multisigs that use this deployer as
+    /// their upgrade registry can only propose upgrades to published wasm hashes.
     #[allow(dead_code)]
+    pub fn publish_wasm_hash(env: Env, wasm_hash: BytesN<32>, audit_note: String) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        set_published_wasm(&env, &wasm_hash, &audit_note);
+
+        env.events()
+            .publish(("Multisig Deployer", "Publish wasm hash"), wasm_hash);
+        env.events()
+            .publish(("Multisig Deployer", "Publish audit note"), audit_note);
+    }
+
+    #[allow(dead_code)]
+    pub fn revoke_wasm_hash(env: Env, wasm_hash: BytesN<32>) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        remove_published_wasm(&env, &wasm_hash);
+
+        env.events()
+            .publish(("Multisig Deployer", "Revoke wasm hash"), wasm_hash);
+    }
+
+    /// Returns the audit note of a published wasm hash, `None` if it wasn't published
+    #[allow(dead_code)]
+    pub fn query_published_wasm(env: Env, wasm_hash: BytesN<32>) -> Option<String> {
+        get_published_wasm(&env, &wasm_hash)
+    }
+
+    #[allow(dead_code, clippy::too_many_arguments)]
     pub fn deploy_new_multisig(
         env: Env,
         deployer: Address,
@@ -44,6 +78,7 @@ impl MultisigDeployer {
         description: String,
         members: Vec<Address>,
         quorum_bps: Option<u32>,
+        restrict_upgrades: bool,
     ) -> Address {
         deployer.require_auth();
         let multisig_wasm_hash = get_wasm_hash(&env);
@@ -51,7 +86,7 @@ impl MultisigDeployer {
         let deployed_multisig = env
             .deployer()
             .with_address(deployer, salt)
-            .deploy(multisig_wasm_hash);
+            .deploy(multisig_wasm_hash.clone());
 
         let init_fn = Symbol::new(&env, "initialize");
         let init_fn_args: Vec<Val> = vec![
@@ -60,6 +95,11 @@ impl MultisigDeployer {
             description.into_val(&env),
             members.into_val(&env),
             quorum_bps.into_val(&env),
+            // a restricted multisig can only be upgraded to releases published here
+            restrict_upgrades
+                .then(|| env.current_contract_address())
+                .into_val(&env),
+            Some(multisig_wasm_hash).into_val(&env),
         ];
         let _: Val = env.invoke_contract(&deployed_multisig, &init_fn, init_fn_args);
 
@@ -74,6 +114,9 @@ impl MultisigDeployer {
 pub enum DataKey {
     IsInitialized,
     MultisigWasmHash,
+    Admin,
+    // Audit note of a published multisig release, kept in persistent storage
+    PublishedWasm(BytesN<32>),
 }
 
 pub fn set_initialized(env: &Env) {
@@ -129,5 +172,47 @@ pub fn get_wasm_hash(env: &Env) -> BytesN<32> {
     wasm_hash
 }
 
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+    env.storage()
+        .instance()
+        .extend_ttl(LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn get_admin(env: &Env) -> Address {
+    let admin = env.storage().instance().get(&DataKey::Admin).unwrap();
+    env.storage()
+        .instance()
+        .extend_ttl(LIFETIME_THRESHOLD, BUMP_AMOUNT);
+
+    admin
+}
+
+pub fn set_published_wasm(env: &Env, hash: &BytesN<32>, audit_note: &String) {
+    let key = DataKey::PublishedWasm(hash.clone());
+    env.storage().persistent().set(&key, audit_note);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn remove_published_wasm(env: &Env, hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PublishedWasm(hash.clone()));
+}
+
+pub fn get_published_wasm(env: &Env, hash: &BytesN<32>) -> Option<String> {
+    let key = DataKey::PublishedWasm(hash.clone());
+    let audit_note = env.storage().persistent().get(&key);
+    env.storage().persistent().has(&key).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    audit_note
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,6 +1,11 @@
+extern crate std;
+
 use crate::{MultisigDeployer, MultisigDeployerClient};
 #[cfg(test)]
-use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String, Symbol, Val, Vec};
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
+    vec, Address, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
+};
 
 // The contract that will be deployed by the deployer contract.
 #[allow(clippy::too_many_arguments)]
@@ -13,15 +18,15 @@ mod multisig {
 #[test]
 fn test_deploy_multisig_from_contract() {
     let env = Env::default();
+    env.budget().reset_unlimited();
     let deployer_client =
         MultisigDeployerClient::new(&env, &env.register_contract(None, MultisigDeployer));
 
     // Upload the Wasm to be deployed from the deployer contract.
     // This can also be called from within a contract if needed.
     let wasm_hash = env.deployer().upload_contract_wasm(multisig::WASM);
-    deployer_client.initialize(&wasm_hash);
-
     env.mock_all_auths();
+    deployer_client.initialize(&Address::generate(&env), &wasm_hash);
 
     let salt = BytesN::from_array(&env, &[0; 32]);
     let msig_members = vec![&env, Address::generate(&env), Address::generate(&env)];
@@ -33,6 +38,7 @@ fn test_deploy_multisig_from_contract() {
         &String::from_str(&env, "TestMSig description"),
         &msig_members,
         &None::<u32>,
+        &true,
     );
 
     // now verify the deployment
@@ -41,6 +47,28 @@ fn test_deploy_multisig_from_contract() {
     env.mock_all_auths();
     let members_result: Vec<Address> = env.invoke_contract(&deployed_multisig, &query, arguments);
     assert_eq!(members_result, msig_members);
+
+    // the deployer is set as the upgrade registry of the new multisig
+    let query = Symbol::new(&env, "query_upgrade_registry");
+    let registry: Option<Address> = env.invoke_contract(&deployed_multisig, &query, vec![&env]);
+    assert_eq!(registry, Some(deployer_client.address.clone()));
+
+    // and the deployed code can be restored by a rollback
+    let info = multisig::Client::new(&env, &deployed_multisig).query_multisig_info();
+    assert_eq!(info.upgrade_history.get(0).unwrap().wasm_hash, wasm_hash);
+
+    // unrestricted multisigs can be upgraded to any code
+    let unrestricted_multisig = deployer_client.deploy_new_multisig(
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[1; 32]),
+        &String::from_str(&env, "TestMSig"),
+        &String::from_str(&env, "TestMSig description"),
+        &msig_members,
+        &None::<u32>,
+        &false,
+    );
+    let registry: Option<Address> = env.invoke_contract(&unrestricted_multisig, &query, vec![&env]);
+    assert_eq!(registry, None);
 }
 
 #[test]
@@ -53,6 +81,60 @@ fn initialize_twice() {
         MultisigDeployerClient::new(&env, &env.register_contract(None, MultisigDeployer));
 
     let wasm_hash = env.deployer().upload_contract_wasm(multisig::WASM);
-    deployer_client.initialize(&wasm_hash);
-    deployer_client.initialize(&wasm_hash);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    deployer_client.initialize(&admin, &wasm_hash);
+    deployer_client.initialize(&admin, &wasm_hash);
+}
+
+#[test]
+fn publish_and_revoke_wasm_hash() {
+    let env = Env::default();
+    let deployer_client =
+        MultisigDeployerClient::new(&env, &env.register_contract(None, MultisigDeployer));
+
+    let admin = Address::generate(&env);
+    let wasm_hash = env.deployer().upload_contract_wasm(multisig::WASM);
+    env.mock_all_auths();
+    deployer_client.initialize(&admin, &wasm_hash);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    deployer_client.address.clone(),
+                    Symbol::new(&env, "initialize"),
+                    (admin.clone(), wasm_hash.clone()).into_val(&env),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+
+    assert_eq!(deployer_client.query_published_wasm(&wasm_hash), None);
+
+    let audit_note = String::from_str(&env, "v1.0.0 audited by Example Security, report #42");
+    deployer_client.publish_wasm_hash(&wasm_hash, &audit_note);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    deployer_client.address.clone(),
+                    Symbol::new(&env, "publish_wasm_hash"),
+                    (wasm_hash.clone(), audit_note.clone()).into_val(&env),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(
+        deployer_client.query_published_wasm(&wasm_hash),
+        Some(audit_note)
+    );
+
+    deployer_client.revoke_wasm_hash(&wasm_hash);
+    assert_eq!(deployer_client.query_published_wasm(&wasm_hash), None);
 }
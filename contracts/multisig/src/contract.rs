@@ -1,18 +1,42 @@
 use soroban_sdk::{
-    contract, contractimpl, contractmeta, log, panic_with_error, vec, Address, BytesN, Env, String,
-    Vec,
+    contract, contractimpl, contractmeta, log, panic_with_error, vec, Address, Bytes, BytesN, Env,
+    InvokeError, String, Symbol, Val, Vec,
 };
 
 use crate::{
     error::ContractError,
+    migration::migrate_proposal,
+    price_oracle,
     storage::{
-        get_last_proposal_id, get_multisig_members, get_name, get_proposal,
-        get_proposal_signatures, get_quorum_bps, get_version, increase_version,
-        increment_last_proposal_id, is_initialized, save_new_multisig, save_proposal,
-        save_proposal_signature, save_quorum_bps, save_version, set_initialized, set_name,
-        MultisigInfo, Proposal, ProposalStatus, ProposalType, Transaction,
+        get_address_book, get_amendments, get_approval_stages, get_blocklist,
+        get_budget_exceeding_quorum_bps, get_budget_usage, get_budgets, get_claim,
+        get_claim_window, get_delegations, get_dependencies, get_dependents, get_deposit,
+        get_execution_condition, get_last_deposit_id, get_last_proposal_id, get_last_stream_id,
+        get_last_vesting_id, get_migration_progress, get_multisig_members, get_name, get_proposal,
+        get_proposal_signatures, get_quorum_bps, get_reservations, get_rollback_quorum_bps,
+        get_signer_roles, get_spenders, get_storage_version, get_stream, get_tracked_tokens,
+        get_unsettled_proposal_cursor, get_upgrade_history, get_upgrade_registry, get_version,
+        get_vesting, increase_version, increment_amendments, increment_last_deposit_id,
+        increment_last_proposal_id, increment_last_stream_id, increment_last_vesting_id,
+        is_address_book_strict, is_budget_exceeding, is_initialized, is_strict_sequence,
+        is_swap_escrowed, remove_claim, remove_unsettled_proposal_cursor, save_address_book,
+        save_address_book_strict_mode, save_approval_stages, save_blocklist, save_budget_exceeding,
+        save_budget_exceeding_quorum_bps, save_budget_usage, save_budgets, save_claim,
+        save_claim_window, save_delegations, save_dependencies, save_dependents, save_deposit,
+        save_execution_condition, save_migration_progress, save_new_multisig, save_proposal,
+        save_proposal_signature, save_quorum_bps, save_reservations, save_rollback_quorum_bps,
+        save_signer_roles, save_spenders, save_storage_version, save_stream, save_strict_sequence,
+        save_swap_escrowed, save_tracked_tokens, save_unsettled_proposal_cursor,
+        save_upgrade_history, save_upgrade_registry, save_version, save_vesting, set_initialized,
+        set_name, AddressBookEntry, AddressBookKind, AddressBookUpdate, Allowance,
+        AllowanceRevocation, ApprovalStage, BlocklistUpdate, BudgetPeriod, BudgetStatus,
+        BudgetUsage, Burn, Claim, ConfigUpdate, ContractMigration, Delegation, Deposit,
+        ExecutionCondition, MigrationProgress, Mint, MultisigInfo, Proposal, ProposalStatus,
+        ProposalType, SignerRoles, Stream, StreamAction, Swap, TokenAdmin, TokenFlows, Transaction,
+        TransferFrom, TreasuryBalance, UpgradeRecord, Vesting, VestingAction,
     },
-    token_contract, ONE_HOUR, SEVEN_DAYS_EXPIRATION_DATE, SOROBAN_ZERO_ADDRESS,
+    token_contract, upgrade_registry, MAX_SEQUENCE_STEPS, NO_EXPIRATION, ONE_DAY, ONE_HOUR,
+    SEVEN_DAYS_EXPIRATION_DATE, SOROBAN_ZERO_ADDRESS, STORAGE_VERSION,
 };
 use soroban_decimal::Decimal;
 
@@ -35,6 +59,8 @@ impl Multisig {
         description: String,
         members: Vec<Address>,
         quorum_bps: Option<u32>,
+        upgrade_registry: Option<Address>,
+        deployed_wasm_hash: Option<BytesN<32>>,
     ) -> Result<(), ContractError> {
         verify_members(&env, &members);
 
@@ -84,7 +110,20 @@ impl Multisig {
             save_quorum_bps(&env, quorum_bps);
         }
 
+        // multisigs created by the deployer only accept releases it published
+        save_upgrade_registry(&env, &upgrade_registry);
+        // the code the contract was deployed with is the first rollback target
+        if let Some(wasm_hash) = deployed_wasm_hash {
+            let record = UpgradeRecord {
+                wasm_hash,
+                timestamp: env.ledger().timestamp(),
+                proposal_id: 0,
+                with_migration: false,
+            };
+            save_upgrade_history(&env, &vec![&env, record]);
+        }
         save_version(&env, &0);
+        save_storage_version(&env, STORAGE_VERSION);
 
         env.events().publish(("Multisig", "Initialize name"), name);
         env.events()
@@ -100,75 +139,202 @@ impl Multisig {
         title: String,
         description: String,
         recipient: Address,
-        amount: u64,
+        amount: i128,
         token: Address,
         expiration_date: Option<u64>,
     ) -> Result<(), ContractError> {
         sender.require_auth();
 
-        let multisig = get_multisig_members(&env);
-
-        // check if sender is a member of this multisig
-        if multisig.get(sender.clone()).is_none() {
+        if amount <= 0 {
             log!(
                 &env,
-                "Multisig: Create transaction proposal: Sender is not a member of this multisig!"
+                "Multisig: Create transaction proposal: Amount has to be positive!"
             );
-            return Err(ContractError::UnauthorizedNotAMember);
+            return Err(ContractError::InvalidAmount);
         }
 
-        // check if title and description aren't too long
-        if title.len() > 64 {
-            log!(
-                &env,
-                "Multisig: Create transaction proposal: Title longer than 64 characters!"
-            );
-            return Err(ContractError::TitleTooLong);
-        }
-        if description.len() > 256 {
-            log!(
-                &env,
-                "Multisig: Create transaction proposal: Description longer than 256 characters!"
-            );
-            return Err(ContractError::DescriptionTooLong);
-        }
+        verify_not_blocked(&env, &recipient)?;
+        verify_address_book(&env, &recipient, &token)?;
 
-        // loads the previous id, returns it and increments before saving
-        let proposal_id = increment_last_proposal_id(&env);
         let transaction = Transaction {
-            token,
+            token: token.clone(),
             amount,
             recipient,
         };
-
-        let creation_timestamp = env.ledger().timestamp();
-        let expiration_timestamp = creation_timestamp
-            + expiration_date.unwrap_or(creation_timestamp + SEVEN_DAYS_EXPIRATION_DATE);
-        if expiration_timestamp < creation_timestamp + ONE_HOUR {
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::Transaction(transaction),
+            expiration_date,
+        )?;
+
+        // funds committed to open proposals, including this one, can't be promised twice
+        let balance =
+            token_contract::Client::new(&env, &token).balance(&env.current_contract_address());
+        if balance < get_reserved_amount(&env, &token) {
             log!(
                 &env,
-                "Multisig: Create Transaction proposal: Expiration date cannot be less than an hour."
+                "Multisig: Create transaction proposal: Not enough unreserved funds!"
             );
-            return Err(ContractError::InvalidExpirationDate);
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        Ok(())
+    }
+
+    /// Creates a proposal that changes the configuration of the multisig once executed
+    #[allow(dead_code)]
+    pub fn create_config_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        config: ConfigUpdate,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        match &config {
+            ConfigUpdate::SignerRoles(signer_roles) => {
+                let multisig = get_multisig_members(&env);
+                if signer_roles
+                    .mandatory_signers
+                    .iter()
+                    .chain(signer_roles.veto_holders.iter())
+                    .any(|address| !multisig.contains_key(address))
+                {
+                    log!(
+                        &env,
+                        "Multisig: Create config proposal: Signer roles can only be given to members!"
+                    );
+                    return Err(ContractError::UnauthorizedNotAMember);
+                }
+            }
+            ConfigUpdate::ApprovalStages(stages) => verify_approval_stages(&env, stages)?,
+            ConfigUpdate::UpgradeRegistry(_) | ConfigUpdate::RemoveUpgradeRegistry => {}
+            ConfigUpdate::RollbackQuorumBps(quorum_bps)
+            | ConfigUpdate::BudgetExceedingQuorumBps(quorum_bps) => {
+                if *quorum_bps <= 100 {
+                    log!(
+                        &env,
+                        "Multisig: Create config proposal: Quorum BPS amount set to 100 or lower"
+                    );
+                    return Err(ContractError::InitializeTooLowQuorum);
+                }
+                if *quorum_bps > 10_000 {
+                    log!(
+                        &env,
+                        "Multisig: Create config proposal: Quorum BPS amount set to more than 100%!"
+                    );
+                    return Err(ContractError::InitializeTooHighQuorum);
+                }
+            }
+            ConfigUpdate::Budget(budget) => {
+                if budget.amount <= 0 || budget.period == BudgetPeriod::Seconds(0) {
+                    log!(
+                        &env,
+                        "Multisig: Create config proposal: Budget amount and period have to be positive!"
+                    );
+                    return Err(ContractError::InvalidAmount);
+                }
+            }
+            ConfigUpdate::RemoveBudget(_)
+            | ConfigUpdate::StrictSequence(_)
+            | ConfigUpdate::TrackToken(_)
+            | ConfigUpdate::UntrackToken(_) => {}
         }
 
-        let proposal = Proposal {
-            id: proposal_id,
-            sender: sender.clone(),
-            proposal: ProposalType::Transaction(transaction),
-            status: ProposalStatus::Open,
-            creation_timestamp,
-            expiration_timestamp,
-            title: title.clone(),
+        create_proposal(
+            &env,
+            sender,
+            title,
             description,
-        };
+            ProposalType::UpdateConfig(config),
+            expiration_date,
+        )?;
 
-        save_proposal(&env, &proposal);
+        Ok(())
+    }
 
-        env.events()
-            .publish(("Multisig", "Create proposal Title"), title);
-        env.events()
-            .publish(("Multisig", "Create proposal Sender"), sender);
+    /// Creates a proposal that adds or removes an address book entry, or toggles
+    /// the strict mode that limits transactions to the address book
+    #[allow(dead_code)]
+    pub fn create_address_book_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        update: AddressBookUpdate,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        match &update {
+            AddressBookUpdate::Add(entry) => {
+                if entry.label.len() > 64 {
+                    log!(
+                        &env,
+                        "Multisig: Create address book proposal: Label longer than 64 characters!"
+                    );
+                    return Err(ContractError::LabelTooLong);
+                }
+            }
+            AddressBookUpdate::Remove(address, kind) => {
+                if !get_address_book(&env).contains_key((address.clone(), kind.clone())) {
+                    log!(
+                        &env,
+                        "Multisig: Create address book proposal: Address is not in the address book!"
+                    );
+                    return Err(ContractError::AddressNotInAddressBook);
+                }
+            }
+            AddressBookUpdate::SetStrictMode(_) => {}
+        }
+
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::UpdateAddressBook(update),
+            expiration_date,
+        )?;
+
+        Ok(())
+    }
+
+    /// Creates a proposal that adds or removes an address from the blocklist
+    #[allow(dead_code)]
+    pub fn create_blocklist_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        update: BlocklistUpdate,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        if let BlocklistUpdate::Remove(address) = &update {
+            if !get_blocklist(&env).contains_key(address.clone()) {
+                log!(
+                    &env,
+                    "Multisig: Create blocklist proposal: Address is not on the blocklist!"
+                );
+                return Err(ContractError::AddressNotBlocked);
+            }
+        }
+
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::UpdateBlocklist(update),
+            expiration_date,
+        )?;
 
         Ok(())
     }
@@ -184,279 +350,3013 @@ impl Multisig {
     ) -> Result<(), ContractError> {
         sender.require_auth();
 
-        let multisig = get_multisig_members(&env);
+        verify_wasm_hash_published(&env, &new_wasm_hash)?;
 
-        if multisig.get(sender.clone()).is_none() {
-            log!(
-                &env,
-                "Multisig: Create update proposal: Sender is not a member of this multisig!"
-            );
-            return Err(ContractError::UnauthorizedNotAMember);
-        }
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::UpdateContract(new_wasm_hash),
+            expiration_date,
+        )?;
+
+        Ok(())
+    }
 
-        let proposal_id = increment_last_proposal_id(&env);
-        let creation_timestamp = env.ledger().timestamp();
-        let expiration_timestamp = creation_timestamp
-            + expiration_date.unwrap_or(creation_timestamp + SEVEN_DAYS_EXPIRATION_DATE);
+    /// Creates a proposal that updates the contract's wasm and schedules a storage
+    /// migration from `from_version`, which has to be the current storage version.
+    /// After execution the migration is run by calling `migrate`.
+    #[allow(dead_code)]
+    pub fn create_migration_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        new_wasm_hash: BytesN<32>,
+        from_version: u32,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
 
-        if expiration_timestamp < creation_timestamp + ONE_HOUR {
+        if from_version != get_storage_version(&env) {
             log!(
                 &env,
-                "Multisig: Create Update proposal: Expiration date cannot be less than an hour."
+                "Multisig: Create migration proposal: Migration has to start from the current storage version!"
             );
-            panic_with_error!(&env, ContractError::InvalidExpirationDate);
+            return Err(ContractError::InvalidMigrationVersion);
         }
 
-        let proposal = Proposal {
-            id: proposal_id,
-            sender: sender.clone(),
-            proposal: ProposalType::UpdateContract(new_wasm_hash),
-            status: ProposalStatus::Open,
-            creation_timestamp,
-            expiration_timestamp,
-            title: title.clone(),
+        verify_wasm_hash_published(&env, &new_wasm_hash)?;
+
+        create_proposal(
+            &env,
+            sender,
+            title,
             description,
-        };
-        save_proposal(&env, &proposal);
+            ProposalType::UpdateContractWithMigration(ContractMigration {
+                new_wasm_hash,
+                from_version,
+            }),
+            expiration_date,
+        )?;
 
-        env.events()
-            .publish(("Multisig", "Create proposal id"), proposal_id);
-        env.events()
-            .publish(("Multisig", "Create proposal sender"), sender);
+        Ok(())
+    }
+
+    /// Creates a proposal that restores the wasm hash installed before the latest
+    /// upgrade. It uses the rollback quorum, if configured, and skips the approval
+    /// stages so a broken release can be reverted quickly.
+    #[allow(dead_code)]
+    pub fn create_rollback_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        let previous_wasm_hash = get_rollback_target(&env)?;
+        verify_wasm_hash_published(&env, &previous_wasm_hash)?;
+
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::Rollback(previous_wasm_hash),
+            expiration_date,
+        )?;
 
         Ok(())
     }
 
+    /// Creates a proposal that starts, adjusts or cancels a payment stream. Once a
+    /// stream is created, its recipient withdraws the accrued amount with `claim_stream`.
     #[allow(dead_code)]
-    pub fn sign_proposal(env: Env, sender: Address, proposal_id: u64) -> Result<(), ContractError> {
+    pub fn create_stream_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        action: StreamAction,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
         sender.require_auth();
 
-        let multisig = get_multisig_members(&env);
+        let curr_timestamp = env.ledger().timestamp();
+        match &action {
+            StreamAction::Create(terms) => {
+                if terms.amount_per_period <= 0
+                    || terms.period == 0
+                    || terms.end_timestamp <= terms.start_timestamp
+                    || terms.end_timestamp <= curr_timestamp
+                {
+                    log!(
+                        &env,
+                        "Multisig: Create stream proposal: Invalid stream schedule!"
+                    );
+                    return Err(ContractError::InvalidStream);
+                }
+                verify_not_blocked(&env, &terms.recipient)?;
+                verify_address_book(&env, &terms.recipient, &terms.token)?;
+            }
+            StreamAction::Update(update) => {
+                if get_stream(&env, update.stream_id).is_none() {
+                    log!(
+                        &env,
+                        "Multisig: Create stream proposal: Stream with this ID does not exist!"
+                    );
+                    return Err(ContractError::StreamNotFound);
+                }
+                if update.amount_per_period <= 0
+                    || update.period == 0
+                    || update.end_timestamp <= curr_timestamp
+                {
+                    log!(
+                        &env,
+                        "Multisig: Create stream proposal: Invalid stream schedule!"
+                    );
+                    return Err(ContractError::InvalidStream);
+                }
+            }
+            StreamAction::Cancel(stream_id) => {
+                if get_stream(&env, *stream_id).is_none() {
+                    log!(
+                        &env,
+                        "Multisig: Create stream proposal: Stream with this ID does not exist!"
+                    );
+                    return Err(ContractError::StreamNotFound);
+                }
+            }
+        }
 
-        let proposal = match get_proposal(&env, proposal_id) {
-            Some(proposal) => proposal,
-            None => {
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::Stream(action.clone()),
+            expiration_date,
+        )?;
+
+        // the whole stream is reserved, like the amount of a transaction proposal
+        if let StreamAction::Create(terms) = action {
+            let balance = token_contract::Client::new(&env, &terms.token)
+                .balance(&env.current_contract_address());
+            if balance < get_reserved_amount(&env, &terms.token) {
                 log!(
                     &env,
-                    "Multisig: Sign proposal: Proposal with this ID does not exist!"
+                    "Multisig: Create stream proposal: Not enough unreserved funds!"
                 );
-                return Err(ContractError::ProposalNotFound);
+                return Err(ContractError::InsufficientBalance);
             }
-        };
-
-        // check if sender is a member of this multisig
-        if multisig.get(sender.clone()).is_none() {
-            log!(
-                &env,
-                "Multisig: Sign proposal: Sender is not a member of this multisig!"
-            );
-            return Err(ContractError::UnauthorizedNotAMember);
-        }
-
-        if proposal.status != ProposalStatus::Open {
-            log!(
-                &env,
-                "Multisig: Sign proposal: Trying to sign a closed proposal!"
-            );
-            return Err(ContractError::ProposalClosed);
         }
 
-        save_proposal_signature(&env, proposal_id, sender.clone());
-
-        env.events()
-            .publish(("Multisig", "Sign proposal ID: "), proposal_id);
-        env.events()
-            .publish(("Multisig", "Sign proposal sender"), sender);
-
         Ok(())
     }
 
+    /// Creates a proposal that starts a vesting schedule funded from the treasury or
+    /// revokes one. The beneficiary withdraws the vested amount with `claim_vested`.
     #[allow(dead_code)]
-    pub fn execute_proposal(
+    pub fn create_vesting_proposal(
         env: Env,
         sender: Address,
-        proposal_id: u64,
+        title: String,
+        description: String,
+        action: VestingAction,
+        expiration_date: Option<u64>,
     ) -> Result<(), ContractError> {
         sender.require_auth();
 
-        let mut proposal = match get_proposal(&env, proposal_id) {
-            Some(proposal) => proposal,
-            None => {
+        match &action {
+            VestingAction::Create(terms) => {
+                if terms.amount <= 0
+                    || terms.cliff_timestamp < terms.start_timestamp
+                    || terms.end_timestamp < terms.cliff_timestamp
+                    || terms.end_timestamp <= terms.start_timestamp
+                    || terms.end_timestamp <= env.ledger().timestamp()
+                {
+                    log!(
+                        &env,
+                        "Multisig: Create vesting proposal: Invalid vesting schedule!"
+                    );
+                    return Err(ContractError::InvalidVesting);
+                }
+                verify_not_blocked(&env, &terms.beneficiary)?;
+                verify_address_book(&env, &terms.beneficiary, &terms.token)?;
+            }
+            VestingAction::Revoke(vesting_id) => {
+                let vesting =
+                    get_vesting(&env, *vesting_id).ok_or(ContractError::VestingNotFound)?;
+                if vesting.revoked_timestamp.is_some() {
+                    log!(
+                        &env,
+                        "Multisig: Create vesting proposal: Vesting is already revoked!"
+                    );
+                    return Err(ContractError::InvalidVesting);
+                }
+            }
+        }
+
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::Vesting(action.clone()),
+            expiration_date,
+        )?;
+
+        // the whole vesting amount is reserved, like the amount of a transaction proposal
+        if let VestingAction::Create(terms) = action {
+            let balance = token_contract::Client::new(&env, &terms.token)
+                .balance(&env.current_contract_address());
+            if balance < get_reserved_amount(&env, &terms.token) {
                 log!(
                     &env,
-                    "Multisig: Sign proposal: Proposal with this ID does not exist!"
+                    "Multisig: Create vesting proposal: Not enough unreserved funds!"
                 );
-                return Err(ContractError::ProposalNotFound);
+                return Err(ContractError::InsufficientBalance);
             }
-        };
+        }
 
-        let multisig = get_multisig_members(&env);
+        Ok(())
+    }
 
-        // check if sender is a member of this multisig
-        if multisig.get(sender.clone()).is_none() {
+    /// Creates a proposal that allows `spender` to transfer up to `amount` of the
+    /// multisig's tokens until `expiration_ledger`, through the token's `approve`
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn create_approve_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        token: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        if amount <= 0 {
             log!(
                 &env,
-                "Multisig: Execute proposal: Sender is not a member of this multisig!"
+                "Multisig: Create approve proposal: Amount has to be positive!"
             );
-            return Err(ContractError::UnauthorizedNotAMember);
+            return Err(ContractError::InvalidAmount);
         }
+        verify_expiration_ledger(&env, expiration_ledger)?;
+        verify_not_blocked(&env, &spender)?;
+        verify_address_book(&env, &spender, &token)?;
 
-        // to prevent a double execution
-        if proposal.status != ProposalStatus::Open {
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::Approve(Allowance {
+                token: token.clone(),
+                spender,
+                amount,
+                expiration_ledger,
+            }),
+            expiration_date,
+        )?;
+
+        // the approved amount is reserved, like the amount of a transaction proposal
+        let balance =
+            token_contract::Client::new(&env, &token).balance(&env.current_contract_address());
+        if balance < get_reserved_amount(&env, &token) {
             log!(
                 &env,
-                "Multisig: Execute proposal: Trying to execute a closed proposal!"
+                "Multisig: Create approve proposal: Not enough unreserved funds!"
             );
-            return Err(ContractError::ProposalClosed);
+            return Err(ContractError::InsufficientBalance);
         }
 
-        let curr_timestamp = env.ledger().timestamp();
-        if curr_timestamp > proposal.expiration_timestamp {
-            log!(
-                &env,
-                "Multisig: Execute proposal: Trying to execute an expired proposal!"
-            );
-            proposal.status = ProposalStatus::Closed;
-            save_proposal(&env, &proposal);
+        Ok(())
+    }
 
-            return Err(ContractError::ProposalExpired);
-        }
+    /// Creates a proposal that sets the allowance of `spender` over the multisig's
+    /// tokens to zero
+    #[allow(dead_code)]
+    pub fn create_revoke_allowance_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        token: Address,
+        spender: Address,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
 
-        // collect all addresses that signed this proposal
-        let proposal_signatures = get_proposal_signatures(&env, proposal_id);
-        let multisig = multisig.keys();
-        let multisig_len = multisig.len();
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::RevokeAllowance(AllowanceRevocation { token, spender }),
+            expiration_date,
+        )?;
 
-        let mut signed = 0i8;
-        for member in multisig {
-            if proposal_signatures.get(member).is_some() {
-                signed += 1;
-            }
-        }
+        Ok(())
+    }
 
-        // get required quorum and compare it with ratio of vote confirmations vs multisig len
-        let required_quorum = Decimal::bps(get_quorum_bps(&env) as i64);
-        let voted_ratio = Decimal::from_ratio(signed, multisig_len);
-        if voted_ratio < required_quorum {
+    /// Creates a proposal that transfers tokens of `from`, who approved the multisig
+    /// as spender, to `recipient`. Tokens collected into the multisig count as inflow.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn create_transfer_from_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        token: Address,
+        from: Address,
+        recipient: Address,
+        amount: i128,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        if amount <= 0 {
             log!(
                 &env,
-                "Multisig: Execute proposal: Required quorum has not been reached!"
+                "Multisig: Create transfer from proposal: Amount has to be positive!"
             );
-            return Err(ContractError::QuorumNotReached);
+            return Err(ContractError::InvalidAmount);
+        }
+        if recipient != env.current_contract_address() {
+            verify_not_blocked(&env, &recipient)?;
+            verify_address_book(&env, &recipient, &token)?;
         }
 
-        // execute actual proposal
-        match proposal.proposal.clone() {
-            // Transaction proposal - transfer tokens to the recipient
-            ProposalType::Transaction(t) => {
-                token_contract::Client::new(&env, &t.token).transfer(
-                    &env.current_contract_address(),
-                    &t.recipient,
-                    &(t.amount as i128),
-                );
-            }
-            ProposalType::UpdateContract(new_wasm_hash) => {
-                env.deployer().update_current_contract_wasm(new_wasm_hash);
-                increase_version(&env);
-            }
-        }
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::TransferFrom(TransferFrom {
+                token,
+                from,
+                recipient,
+                amount,
+            }),
+            expiration_date,
+        )?;
 
-        // after proposal is executed, mark it as closed
-        proposal.status = ProposalStatus::Closed;
-        save_proposal(&env, &proposal);
+        Ok(())
+    }
 
-        env.events()
-            .publish(("Multisig", "Execute proposal ID: "), proposal_id);
-        env.events()
-            .publish(("Multisig", "Execute proposal sender"), sender);
+    /// Creates a proposal that burns tokens held by the multisig
+    #[allow(dead_code)]
+    pub fn create_burn_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        token: Address,
+        amount: i128,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        if amount <= 0 {
+            log!(
+                &env,
+                "Multisig: Create burn proposal: Amount has to be positive!"
+            );
+            return Err(ContractError::InvalidAmount);
+        }
+
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::Burn(Burn {
+                token: token.clone(),
+                amount,
+            }),
+            expiration_date,
+        )?;
+
+        // burned tokens are reserved like the amount of a transaction proposal
+        let balance =
+            token_contract::Client::new(&env, &token).balance(&env.current_contract_address());
+        if balance < get_reserved_amount(&env, &token) {
+            log!(
+                &env,
+                "Multisig: Create burn proposal: Not enough unreserved funds!"
+            );
+            return Err(ContractError::InsufficientBalance);
+        }
 
         Ok(())
     }
 
-    // ----------- QUERY
+    /// Creates a proposal that mints tokens of a token administered by the multisig.
+    /// Tokens minted to the multisig count as inflow.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn create_mint_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
 
-    #[allow(dead_code)]
-    pub fn query_multisig_info(env: Env) -> Result<MultisigInfo, ContractError> {
-        let (name, description) = get_name(&env);
-        Ok(MultisigInfo {
-            name: name.clone(),
+        if amount <= 0 {
+            log!(
+                &env,
+                "Multisig: Create mint proposal: Amount has to be positive!"
+            );
+            return Err(ContractError::InvalidAmount);
+        }
+        if recipient != env.current_contract_address() {
+            verify_not_blocked(&env, &recipient)?;
+            verify_address_book(&env, &recipient, &token)?;
+        }
+
+        create_proposal(
+            &env,
+            sender,
+            title,
             description,
-            members: get_multisig_members(&env).keys(),
-            quorum_bps: get_quorum_bps(&env),
-            version_proposal: get_version(&env),
-        })
+            ProposalType::Mint(Mint {
+                token,
+                recipient,
+                amount,
+            }),
+            expiration_date,
+        )?;
+
+        Ok(())
     }
 
+    /// Creates a proposal that makes `new_admin` the admin of a token administered by
+    /// the multisig
     #[allow(dead_code)]
-    pub fn query_multisig_members(env: Env) -> Result<Vec<Address>, ContractError> {
-        let multisig_members = get_multisig_members(&env).keys();
-        Ok(multisig_members)
+    pub fn create_set_token_admin_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        token: Address,
+        new_admin: Address,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::SetTokenAdmin(TokenAdmin { token, new_admin }),
+            expiration_date,
+        )?;
+
+        Ok(())
     }
 
+    /// Creates a proposal that sends `send_amount` of `send_token` to the counterparty
+    /// in exchange for `receive_amount` of `receive_token`. The counterparty authorizes
+    /// the call and their side is escrowed right away, so executing the proposal swaps
+    /// both sides atomically. If the proposal expires or gets rejected, the escrow is
+    /// returned with `refund_swap`.
     #[allow(dead_code)]
-    pub fn query_proposal(env: Env, proposal_id: u64) -> Result<Proposal, ContractError> {
-        get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)
+    pub fn create_swap_proposal(
+        env: Env,
+        sender: Address,
+        title: String,
+        description: String,
+        swap: Swap,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+        swap.counterparty.require_auth();
+
+        if swap.send_amount <= 0 || swap.receive_amount <= 0 {
+            log!(
+                &env,
+                "Multisig: Create swap proposal: Amounts have to be positive!"
+            );
+            return Err(ContractError::InvalidAmount);
+        }
+        if swap.send_token == swap.receive_token
+            || swap.counterparty == env.current_contract_address()
+        {
+            log!(
+                &env,
+                "Multisig: Create swap proposal: Tokens have to differ and the counterparty can't be the multisig!"
+            );
+            return Err(ContractError::InvalidSwap);
+        }
+        verify_not_blocked(&env, &swap.counterparty)?;
+        verify_address_book(&env, &swap.counterparty, &swap.send_token)?;
+
+        let proposal_id = create_proposal(
+            &env,
+            sender,
+            title,
+            description,
+            ProposalType::Swap(swap.clone()),
+            expiration_date,
+        )?;
+
+        // the sent side is reserved like the amount of a transaction proposal
+        let balance = token_contract::Client::new(&env, &swap.send_token)
+            .balance(&env.current_contract_address());
+        if balance < get_reserved_amount(&env, &swap.send_token) {
+            log!(
+                &env,
+                "Multisig: Create swap proposal: Not enough unreserved funds!"
+            );
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        token_contract::Client::new(&env, &swap.receive_token).transfer(
+            &swap.counterparty,
+            &env.current_contract_address(),
+            &swap.receive_amount,
+        );
+        save_swap_escrowed(&env, proposal_id, true);
+        update_reservation(
+            &env,
+            &swap.receive_token,
+            NO_EXPIRATION,
+            swap.receive_amount,
+        );
+
+        env.events()
+            .publish(("Multisig", "Swap escrow"), (proposal_id, swap));
+
+        Ok(())
     }
 
     #[allow(dead_code)]
-    pub fn query_signatures(
-        env: Env,
-        proposal_id: u64,
-    ) -> Result<Vec<(Address, bool)>, ContractError> {
+    pub fn sign_proposal(env: Env, sender: Address, proposal_id: u64) -> Result<(), ContractError> {
+        sender.require_auth();
+
         let multisig = get_multisig_members(&env);
-        // collect all addresses that signed this proposal
-        let proposal_signatures = get_proposal_signatures(&env, proposal_id);
 
-        let mut response: Vec<(Address, bool)> = vec![&env];
+        let proposal = match get_proposal(&env, proposal_id) {
+            Some(proposal) => proposal,
+            None => {
+                log!(
+                    &env,
+                    "Multisig: Sign proposal: Proposal with this ID does not exist!"
+                );
+                return Err(ContractError::ProposalNotFound);
+            }
+        };
+
+        // members that delegated their signing right to the sender and whose
+        // delegation is still valid
+        let curr_timestamp = env.ledger().timestamp();
+        let mut delegators: Vec<Address> = vec![&env];
+        for delegation in get_delegations(&env).values() {
+            if delegation.delegate == sender
+                && delegation.expiration_timestamp >= curr_timestamp
+                && multisig.contains_key(delegation.member.clone())
+            {
+                delegators.push_back(delegation.member);
+            }
+        }
 
-        for (member, _) in multisig {
-            if proposal_signatures.get(member.clone()).is_some() {
-                response.push_back((member, true));
-            } else {
-                response.push_back((member, false));
+        // check if sender is a member of this multisig or signs on behalf of one
+        if multisig.get(sender.clone()).is_none() && delegators.is_empty() {
+            log!(
+                &env,
+                "Multisig: Sign proposal: Sender is not a member of this multisig!"
+            );
+            return Err(ContractError::UnauthorizedNotAMember);
+        }
+
+        if proposal.status != ProposalStatus::Open {
+            log!(
+                &env,
+                "Multisig: Sign proposal: Trying to sign a closed proposal!"
+            );
+            return Err(ContractError::ProposalClosed);
+        }
+
+        // with an approval workflow only the signers of the current stage can sign
+        let stage_signers = proposal
+            .stages
+            .get(proposal.current_stage)
+            .map(|stage| stage.signers);
+        let in_current_stage = |member: &Address| match &stage_signers {
+            Some(signers) => signers.contains(member),
+            None => true,
+        };
+
+        let signs_personally = multisig.contains_key(sender.clone()) && in_current_stage(&sender);
+        let mut stage_delegators: Vec<Address> = vec![&env];
+        for member in delegators {
+            if in_current_stage(&member) {
+                stage_delegators.push_back(member);
+            }
+        }
+        if !signs_personally && stage_delegators.is_empty() {
+            log!(
+                &env,
+                "Multisig: Sign proposal: Sender is not a signer of the current approval stage!"
+            );
+            return Err(ContractError::SignerNotInCurrentStage);
+        }
+
+        if signs_personally {
+            save_proposal_signature(&env, proposal_id, sender.clone(), sender.clone());
+        }
+
+        // the signature of a delegate is recorded under the delegating member, unless
+        // that member already signed the proposal personally
+        let proposal_signatures = get_proposal_signatures(&env, proposal_id);
+        for member in stage_delegators {
+            if proposal_signatures.contains_key(member.clone()) {
+                continue;
             }
+            save_proposal_signature(&env, proposal_id, member.clone(), sender.clone());
+            env.events()
+                .publish(("Multisig", "Sign proposal on behalf of"), member);
         }
 
-        Ok(response)
+        let mut proposal = proposal;
+        if advance_approval_stages(&env, &mut proposal) {
+            save_proposal(&env, &proposal);
+        }
+
+        env.events()
+            .publish(("Multisig", "Sign proposal ID: "), proposal_id);
+        env.events()
+            .publish(("Multisig", "Sign proposal sender"), sender);
+
+        Ok(())
     }
 
+    /// Transfers tokens from `from` to the multisig and records the depositor and the
+    /// memo. The amount is added to the token's inflow total if the token is tracked.
     #[allow(dead_code)]
-    pub fn query_last_proposal_id(env: Env) -> Result<u64, ContractError> {
-        let last_id = get_last_proposal_id(&env);
-        Ok(last_id)
+    pub fn deposit(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        memo: String,
+    ) -> Result<u64, ContractError> {
+        from.require_auth();
+
+        if amount <= 0 {
+            log!(&env, "Multisig: Deposit: Amount has to be positive!");
+            return Err(ContractError::InvalidAmount);
+        }
+        if memo.len() > 256 {
+            log!(&env, "Multisig: Deposit: Memo longer than 256 characters!");
+            return Err(ContractError::DescriptionTooLong);
+        }
+
+        token_contract::Client::new(&env, &token).transfer(
+            &from,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        record_inflow(&env, &token, amount);
+
+        let deposit = Deposit {
+            id: increment_last_deposit_id(&env),
+            from,
+            token,
+            amount,
+            memo,
+            timestamp: env.ledger().timestamp(),
+        };
+        save_deposit(&env, &deposit);
+
+        env.events()
+            .publish(("Multisig", "Deposit"), deposit.clone());
+
+        Ok(deposit.id)
     }
 
+    /// Transfers the amount accrued by a stream since the last claim to its recipient
+    /// and returns it
     #[allow(dead_code)]
-    pub fn query_all_proposals(env: Env) -> Result<Vec<Proposal>, ContractError> {
-        let last_proposal_id = get_last_proposal_id(&env);
-        let mut proposals: Vec<Proposal> = vec![&env];
+    pub fn claim_stream(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+    ) -> Result<i128, ContractError> {
+        recipient.require_auth();
 
-        // I think get_proposal should return the option, so that this won't fail in case
-        // of deleted proposal
-        for i in 1..=last_proposal_id {
-            get_proposal(&env, i).is_some().then(|| {
-                let current_prosal = get_proposal(&env, i).unwrap();
-                proposals.push_back(current_prosal);
-            });
+        let mut stream = get_stream(&env, stream_id).ok_or(ContractError::StreamNotFound)?;
+        if stream.recipient != recipient {
+            log!(
+                &env,
+                "Multisig: Claim stream: Sender is not the recipient of the stream!"
+            );
+            return Err(ContractError::Unauthorized);
         }
+        verify_not_blocked(&env, &recipient)?;
 
-        Ok(proposals)
+        let amount = get_stream_accrued(&env, &stream) - stream.claimed;
+        if amount <= 0 {
+            log!(&env, "Multisig: Claim stream: Nothing to claim yet!");
+            return Err(ContractError::InvalidAmount);
+        }
+
+        spend_budget(&env, &stream.token, amount, false)?;
+        let token = token_contract::Client::new(&env, &stream.token);
+        if token.balance(&env.current_contract_address()) < amount {
+            log!(
+                &env,
+                "Multisig: Claim stream: Not enough funds for the payment!"
+            );
+            return Err(ContractError::InsufficientBalance);
+        }
+        token.transfer(&env.current_contract_address(), &recipient, &amount);
+        record_outflow(&env, &stream.token, amount);
+
+        stream.claimed += amount;
+        save_stream(&env, &stream);
+        update_reservation(&env, &stream.token, NO_EXPIRATION, -amount);
+
+        env.events()
+            .publish(("Multisig", "Claim stream"), (stream_id, amount));
+
+        Ok(amount)
     }
 
+    /// Transfers the amount vested since the last claim to the beneficiary and returns it
     #[allow(dead_code)]
-    pub fn is_proposal_ready(env: Env, proposal_id: u64) -> Result<bool, ContractError> {
-        let multisig_len = get_multisig_members(&env).len();
-        let signed = get_proposal_signatures(&env, proposal_id).len();
+    pub fn claim_vested(
+        env: Env,
+        beneficiary: Address,
+        vesting_id: u64,
+    ) -> Result<i128, ContractError> {
+        beneficiary.require_auth();
 
-        let required_quorum = Decimal::bps(get_quorum_bps(&env) as i64);
-        let voted_ratio = Decimal::from_ratio(signed, multisig_len);
+        let mut vesting = get_vesting(&env, vesting_id).ok_or(ContractError::VestingNotFound)?;
+        if vesting.beneficiary != beneficiary {
+            log!(
+                &env,
+                "Multisig: Claim vested: Sender is not the beneficiary of the vesting!"
+            );
+            return Err(ContractError::Unauthorized);
+        }
+        verify_not_blocked(&env, &beneficiary)?;
 
-        if voted_ratio >= required_quorum {
-            Ok(true)
-        } else {
-            Ok(false)
+        let vested_until = match vesting.revoked_timestamp {
+            Some(revoked_timestamp) => revoked_timestamp.min(env.ledger().timestamp()),
+            None => env.ledger().timestamp(),
+        };
+        let amount = get_vested_amount(&vesting, vested_until) - vesting.claimed;
+        if amount <= 0 {
+            log!(&env, "Multisig: Claim vested: Nothing to claim yet!");
+            return Err(ContractError::InvalidAmount);
+        }
+
+        spend_budget(&env, &vesting.token, amount, false)?;
+        let token = token_contract::Client::new(&env, &vesting.token);
+        if token.balance(&env.current_contract_address()) < amount {
+            log!(
+                &env,
+                "Multisig: Claim vested: Not enough funds for the payment!"
+            );
+            return Err(ContractError::InsufficientBalance);
+        }
+        token.transfer(&env.current_contract_address(), &beneficiary, &amount);
+        record_outflow(&env, &vesting.token, amount);
+
+        vesting.claimed += amount;
+        save_vesting(&env, &vesting);
+        update_reservation(&env, &vesting.token, NO_EXPIRATION, -amount);
+
+        env.events()
+            .publish(("Multisig", "Claim vested"), (vesting_id, amount));
+
+        Ok(amount)
+    }
+
+    /// Transfers the tokens of an executed claimable transaction proposal to its
+    /// recipient. After the claim deadline the tokens stay in the treasury.
+    #[allow(dead_code)]
+    pub fn claim(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        let claim = get_claim(&env, proposal_id).ok_or(ContractError::ClaimNotFound)?;
+        claim.recipient.require_auth();
+
+        if env.ledger().timestamp() > claim.deadline {
+            log!(&env, "Multisig: Claim: The claim deadline has passed!");
+            return Err(ContractError::ClaimExpired);
+        }
+        verify_not_blocked(&env, &claim.recipient)?;
+
+        let token = token_contract::Client::new(&env, &claim.token);
+        if token.balance(&env.current_contract_address()) < claim.amount {
+            log!(&env, "Multisig: Claim: Not enough funds for the payment!");
+            return Err(ContractError::InsufficientBalance);
         }
+        token.transfer(
+            &env.current_contract_address(),
+            &claim.recipient,
+            &claim.amount,
+        );
+        record_outflow(&env, &claim.token, claim.amount);
+        remove_claim(&env, proposal_id);
+        update_reservation(&env, &claim.token, claim.deadline, -claim.amount);
+
+        env.events().publish(("Multisig", "Claim"), claim);
+
+        Ok(())
     }
+
+    /// Returns the escrowed side of a swap proposal to the counterparty once the
+    /// proposal expired or got rejected. Anyone can call it.
+    #[allow(dead_code)]
+    pub fn refund_swap(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        let proposal = get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        let ProposalType::Swap(swap) = proposal.proposal else {
+            log!(&env, "Multisig: Refund swap: Proposal is not a swap!");
+            return Err(ContractError::InvalidSwap);
+        };
+
+        let is_pending = proposal.status == ProposalStatus::Open
+            && env.ledger().timestamp() <= proposal.expiration_timestamp;
+        if !is_swap_escrowed(&env, proposal_id) || is_pending {
+            log!(
+                &env,
+                "Multisig: Refund swap: Swap is still pending or already settled!"
+            );
+            return Err(ContractError::SwapNotRefundable);
+        }
+
+        token_contract::Client::new(&env, &swap.receive_token).transfer(
+            &env.current_contract_address(),
+            &swap.counterparty,
+            &swap.receive_amount,
+        );
+        save_swap_escrowed(&env, proposal_id, false);
+        update_reservation(
+            &env,
+            &swap.receive_token,
+            NO_EXPIRATION,
+            -swap.receive_amount,
+        );
+
+        env.events()
+            .publish(("Multisig", "Refund swap"), (proposal_id, swap));
+
+        Ok(())
+    }
+
+    /// Delegates the signing right of a member to another address until the given
+    /// timestamp. A member can have only one active delegation, calling it again
+    /// replaces the previous one.
+    #[allow(dead_code)]
+    pub fn delegate_signing(
+        env: Env,
+        member: Address,
+        delegate: Address,
+        expiration_timestamp: u64,
+    ) -> Result<(), ContractError> {
+        member.require_auth();
+
+        let multisig = get_multisig_members(&env);
+
+        if multisig.get(member.clone()).is_none() {
+            log!(
+                &env,
+                "Multisig: Delegate signing: Sender is not a member of this multisig!"
+            );
+            return Err(ContractError::UnauthorizedNotAMember);
+        }
+
+        if delegate == member {
+            log!(
+                &env,
+                "Multisig: Delegate signing: Member cannot delegate to itself!"
+            );
+            return Err(ContractError::InvalidDelegation);
+        }
+
+        if expiration_timestamp <= env.ledger().timestamp() {
+            log!(
+                &env,
+                "Multisig: Delegate signing: Delegation has to expire in the future!"
+            );
+            return Err(ContractError::InvalidDelegation);
+        }
+
+        let mut delegations = get_delegations(&env);
+        delegations.set(
+            member.clone(),
+            Delegation {
+                member: member.clone(),
+                delegate: delegate.clone(),
+                expiration_timestamp,
+            },
+        );
+        save_delegations(&env, &delegations);
+
+        env.events()
+            .publish(("Multisig", "Delegate signing member"), member);
+        env.events().publish(
+            ("Multisig", "Delegate signing delegate"),
+            (delegate, expiration_timestamp),
+        );
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub fn revoke_delegation(env: Env, member: Address) -> Result<(), ContractError> {
+        member.require_auth();
+
+        let mut delegations = get_delegations(&env);
+        if delegations.remove(member.clone()).is_none() {
+            log!(
+                &env,
+                "Multisig: Revoke delegation: Member has no active delegation!"
+            );
+            return Err(ContractError::DelegationNotFound);
+        }
+        save_delegations(&env, &delegations);
+
+        env.events()
+            .publish(("Multisig", "Revoke delegation member"), member);
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub fn execute_proposal(
+        env: Env,
+        sender: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        let mut proposal = match get_proposal(&env, proposal_id) {
+            Some(proposal) => proposal,
+            None => {
+                log!(
+                    &env,
+                    "Multisig: Sign proposal: Proposal with this ID does not exist!"
+                );
+                return Err(ContractError::ProposalNotFound);
+            }
+        };
+
+        let multisig = get_multisig_members(&env);
+
+        // check if sender is a member of this multisig
+        if multisig.get(sender.clone()).is_none() {
+            log!(
+                &env,
+                "Multisig: Execute proposal: Sender is not a member of this multisig!"
+            );
+            return Err(ContractError::UnauthorizedNotAMember);
+        }
+
+        // to prevent a double execution
+        if proposal.status != ProposalStatus::Open {
+            log!(
+                &env,
+                "Multisig: Execute proposal: Trying to execute a closed proposal!"
+            );
+            return Err(ContractError::ProposalClosed);
+        }
+
+        let curr_timestamp = env.ledger().timestamp();
+        if curr_timestamp > proposal.expiration_timestamp {
+            log!(
+                &env,
+                "Multisig: Execute proposal: Trying to execute an expired proposal!"
+            );
+            proposal.status = ProposalStatus::Closed;
+            save_proposal(&env, &proposal);
+
+            return Err(ContractError::ProposalExpired);
+        }
+        if proposal
+            .not_before
+            .is_some_and(|not_before| curr_timestamp < not_before)
+        {
+            log!(
+                &env,
+                "Multisig: Execute proposal: Trying to execute a proposal before its earliest execution time!"
+            );
+            return Err(ContractError::ProposalNotReady);
+        }
+        verify_execution_order(&env, proposal_id)?;
+
+        check_quorum(&env, &proposal)?;
+        verify_execution_condition(&env, proposal_id)?;
+
+        // a blocked payee rejects the proposal for good, which is recorded in an event
+        if let Some(payee) = get_proposal_payee(&env, &proposal.proposal) {
+            if get_blocklist(&env).contains_key(payee.clone()) {
+                log!(
+                    &env,
+                    "Multisig: Execute proposal: Recipient is on the blocklist, rejecting the proposal!"
+                );
+                close_proposal(&env, &mut proposal, ProposalStatus::Rejected);
+                cancel_dependents(&env, proposal_id);
+                env.events()
+                    .publish(("Multisig", "Blocked recipient"), (proposal_id, payee));
+
+                return Ok(());
+            }
+        }
+
+        // execute actual proposal
+        match proposal.proposal.clone() {
+            // Transaction proposal - transfer tokens to the recipient
+            ProposalType::Transaction(t) => {
+                verify_address_book(&env, &t.recipient, &t.token)?;
+                // claimable transactions are charged now, the claim only transfers
+                spend_budget(
+                    &env,
+                    &t.token,
+                    t.amount,
+                    is_budget_exceeding(&env, proposal_id),
+                )?;
+                // claimable transactions are paid out by `claim`
+                if let Some(claim_window) = get_claim_window(&env, proposal_id) {
+                    let claim = Claim {
+                        proposal_id,
+                        recipient: t.recipient,
+                        token: t.token,
+                        amount: t.amount,
+                        deadline: curr_timestamp.saturating_add(claim_window),
+                    };
+                    save_claim(&env, &claim);
+                    update_reservation(&env, &claim.token, claim.deadline, claim.amount);
+                    env.events().publish(("Multisig", "Create claim"), claim);
+                } else {
+                    let token = token_contract::Client::new(&env, &t.token);
+                    if token.balance(&env.current_contract_address()) < t.amount {
+                        log!(
+                            &env,
+                            "Multisig: Execute proposal: Not enough funds for the transaction!"
+                        );
+                        return Err(ContractError::InsufficientBalance);
+                    }
+                    token.transfer(&env.current_contract_address(), &t.recipient, &t.amount);
+                    record_outflow(&env, &t.token, t.amount);
+                }
+            }
+            ProposalType::UpdateContract(new_wasm_hash) => {
+                verify_wasm_hash_published(&env, &new_wasm_hash)?;
+                env.deployer()
+                    .update_current_contract_wasm(new_wasm_hash.clone());
+                increase_version(&env);
+                record_upgrade(&env, new_wasm_hash, proposal_id, false);
+            }
+            ProposalType::UpdateContractWithMigration(migration) => {
+                verify_wasm_hash_published(&env, &migration.new_wasm_hash)?;
+                env.deployer()
+                    .update_current_contract_wasm(migration.new_wasm_hash.clone());
+                increase_version(&env);
+                record_upgrade(&env, migration.new_wasm_hash, proposal_id, true);
+            }
+            ProposalType::Rollback(previous_wasm_hash) => {
+                // another upgrade or rollback could have been executed in the meantime
+                if get_rollback_target(&env)? != previous_wasm_hash {
+                    log!(
+                        &env,
+                        "Multisig: Execute proposal: Rollback target is no longer the previous version!"
+                    );
+                    return Err(ContractError::NoPreviousVersion);
+                }
+                verify_wasm_hash_published(&env, &previous_wasm_hash)?;
+                env.deployer()
+                    .update_current_contract_wasm(previous_wasm_hash.clone());
+                increase_version(&env);
+                record_upgrade(&env, previous_wasm_hash, proposal_id, false);
+            }
+            ProposalType::Stream(action) => match action {
+                StreamAction::Create(terms) => {
+                    verify_address_book(&env, &terms.recipient, &terms.token)?;
+                    let stream = Stream {
+                        id: increment_last_stream_id(&env),
+                        recipient: terms.recipient,
+                        token: terms.token,
+                        amount_per_period: terms.amount_per_period,
+                        period: terms.period,
+                        start_timestamp: terms.start_timestamp,
+                        end_timestamp: terms.end_timestamp,
+                        carried_over: 0,
+                        claimed: 0,
+                    };
+                    save_stream(&env, &stream);
+                    update_reservation(
+                        &env,
+                        &stream.token,
+                        NO_EXPIRATION,
+                        get_stream_reservation(&stream),
+                    );
+                    env.events().publish(("Multisig", "Create stream"), stream);
+                }
+                StreamAction::Update(update) => {
+                    let mut stream =
+                        get_stream(&env, update.stream_id).ok_or(ContractError::StreamNotFound)?;
+                    let reserved = get_stream_reservation(&stream);
+                    settle_stream(&env, &mut stream);
+                    if update.end_timestamp <= stream.start_timestamp {
+                        log!(
+                            &env,
+                            "Multisig: Execute proposal: Stream would end before its current period!"
+                        );
+                        return Err(ContractError::InvalidStream);
+                    }
+                    stream.amount_per_period = update.amount_per_period;
+                    stream.period = update.period;
+                    stream.end_timestamp = update.end_timestamp;
+                    save_stream(&env, &stream);
+                    update_reservation(
+                        &env,
+                        &stream.token,
+                        NO_EXPIRATION,
+                        get_stream_reservation(&stream) - reserved,
+                    );
+                    env.events().publish(("Multisig", "Update stream"), stream);
+                }
+                StreamAction::Cancel(stream_id) => {
+                    let mut stream =
+                        get_stream(&env, stream_id).ok_or(ContractError::StreamNotFound)?;
+                    let reserved = get_stream_reservation(&stream);
+                    settle_stream(&env, &mut stream);
+                    stream.end_timestamp = stream.start_timestamp;
+                    save_stream(&env, &stream);
+                    update_reservation(
+                        &env,
+                        &stream.token,
+                        NO_EXPIRATION,
+                        get_stream_reservation(&stream) - reserved,
+                    );
+                    env.events()
+                        .publish(("Multisig", "Cancel stream"), stream_id);
+                }
+            },
+            ProposalType::Vesting(action) => match action {
+                VestingAction::Create(terms) => {
+                    verify_address_book(&env, &terms.beneficiary, &terms.token)?;
+                    let vesting = Vesting {
+                        id: increment_last_vesting_id(&env),
+                        beneficiary: terms.beneficiary,
+                        token: terms.token,
+                        amount: terms.amount,
+                        start_timestamp: terms.start_timestamp,
+                        cliff_timestamp: terms.cliff_timestamp,
+                        end_timestamp: terms.end_timestamp,
+                        claimed: 0,
+                        revoked_timestamp: None,
+                    };
+                    save_vesting(&env, &vesting);
+                    update_reservation(&env, &vesting.token, NO_EXPIRATION, vesting.amount);
+                    env.events()
+                        .publish(("Multisig", "Create vesting"), vesting);
+                }
+                VestingAction::Revoke(vesting_id) => {
+                    let mut vesting =
+                        get_vesting(&env, vesting_id).ok_or(ContractError::VestingNotFound)?;
+                    if vesting.revoked_timestamp.is_some() {
+                        log!(
+                            &env,
+                            "Multisig: Execute proposal: Vesting is already revoked!"
+                        );
+                        return Err(ContractError::InvalidVesting);
+                    }
+                    let reserved = get_vesting_reservation(&vesting);
+                    vesting.revoked_timestamp = Some(curr_timestamp);
+                    save_vesting(&env, &vesting);
+                    update_reservation(
+                        &env,
+                        &vesting.token,
+                        NO_EXPIRATION,
+                        get_vesting_reservation(&vesting) - reserved,
+                    );
+                    let unvested = vesting.amount - get_vested_amount(&vesting, curr_timestamp);
+                    env.events()
+                        .publish(("Multisig", "Revoke vesting"), (vesting_id, unvested));
+                }
+            },
+            ProposalType::Approve(allowance) => {
+                verify_address_book(&env, &allowance.spender, &allowance.token)?;
+                verify_expiration_ledger(&env, allowance.expiration_ledger)?;
+                // the spender's transfers bypass the multisig, so the whole allowance is charged
+                spend_budget(
+                    &env,
+                    &allowance.token,
+                    allowance.amount,
+                    is_budget_exceeding(&env, proposal_id),
+                )?;
+                token_contract::Client::new(&env, &allowance.token).approve(
+                    &env.current_contract_address(),
+                    &allowance.spender,
+                    &allowance.amount,
+                    &allowance.expiration_ledger,
+                );
+                // the allowance stays reserved until it's used up, revoked or expires
+                let mut spenders = get_spenders(&env, &allowance.token);
+                if !spenders.contains(&allowance.spender) {
+                    spenders.push_back(allowance.spender.clone());
+                    save_spenders(&env, &allowance.token, &spenders);
+                }
+                env.events().publish(("Multisig", "Approve"), allowance);
+            }
+            ProposalType::RevokeAllowance(revocation) => {
+                token_contract::Client::new(&env, &revocation.token).approve(
+                    &env.current_contract_address(),
+                    &revocation.spender,
+                    &0,
+                    &env.ledger().sequence(),
+                );
+                let mut spenders = get_spenders(&env, &revocation.token);
+                if let Some(index) = spenders.first_index_of(&revocation.spender) {
+                    spenders.remove(index);
+                    save_spenders(&env, &revocation.token, &spenders);
+                }
+                env.events()
+                    .publish(("Multisig", "Revoke allowance"), revocation);
+            }
+            ProposalType::TransferFrom(transfer) => {
+                let collected = transfer.recipient == env.current_contract_address();
+                if !collected {
+                    verify_address_book(&env, &transfer.recipient, &transfer.token)?;
+                }
+                token_contract::Client::new(&env, &transfer.token).transfer_from(
+                    &env.current_contract_address(),
+                    &transfer.from,
+                    &transfer.recipient,
+                    &transfer.amount,
+                );
+                if collected {
+                    record_inflow(&env, &transfer.token, transfer.amount);
+                }
+                env.events()
+                    .publish(("Multisig", "Transfer from"), transfer);
+            }
+            ProposalType::Burn(burn) => {
+                let token = token_contract::Client::new(&env, &burn.token);
+                if token.balance(&env.current_contract_address()) < burn.amount {
+                    log!(
+                        &env,
+                        "Multisig: Execute proposal: Not enough funds to burn!"
+                    );
+                    return Err(ContractError::InsufficientBalance);
+                }
+                token.burn(&env.current_contract_address(), &burn.amount);
+                record_outflow(&env, &burn.token, burn.amount);
+                env.events().publish(("Multisig", "Burn"), burn);
+            }
+            ProposalType::Mint(mint) => {
+                let minted_to_treasury = mint.recipient == env.current_contract_address();
+                if !minted_to_treasury {
+                    verify_address_book(&env, &mint.recipient, &mint.token)?;
+                }
+                token_contract::Client::new(&env, &mint.token).mint(&mint.recipient, &mint.amount);
+                if minted_to_treasury {
+                    record_inflow(&env, &mint.token, mint.amount);
+                }
+                env.events().publish(("Multisig", "Mint"), mint);
+            }
+            ProposalType::SetTokenAdmin(token_admin) => {
+                token_contract::Client::new(&env, &token_admin.token)
+                    .set_admin(&token_admin.new_admin);
+                env.events()
+                    .publish(("Multisig", "Set token admin"), token_admin);
+            }
+            ProposalType::Swap(swap) => {
+                verify_address_book(&env, &swap.counterparty, &swap.send_token)?;
+                spend_budget(
+                    &env,
+                    &swap.send_token,
+                    swap.send_amount,
+                    is_budget_exceeding(&env, proposal_id),
+                )?;
+                let token = token_contract::Client::new(&env, &swap.send_token);
+                if token.balance(&env.current_contract_address()) < swap.send_amount {
+                    log!(
+                        &env,
+                        "Multisig: Execute proposal: Not enough funds for the swap!"
+                    );
+                    return Err(ContractError::InsufficientBalance);
+                }
+                token.transfer(
+                    &env.current_contract_address(),
+                    &swap.counterparty,
+                    &swap.send_amount,
+                );
+                save_swap_escrowed(&env, proposal_id, false);
+                update_reservation(
+                    &env,
+                    &swap.receive_token,
+                    NO_EXPIRATION,
+                    -swap.receive_amount,
+                );
+                record_outflow(&env, &swap.send_token, swap.send_amount);
+                record_inflow(&env, &swap.receive_token, swap.receive_amount);
+                env.events().publish(("Multisig", "Swap"), swap);
+            }
+            ProposalType::UpdateConfig(config) => match config {
+                ConfigUpdate::SignerRoles(signer_roles) => {
+                    save_signer_roles(&env, &signer_roles);
+                    env.events()
+                        .publish(("Multisig", "Update signer roles"), signer_roles);
+                }
+                ConfigUpdate::ApprovalStages(stages) => {
+                    save_approval_stages(&env, &stages);
+                    env.events()
+                        .publish(("Multisig", "Update approval stages"), stages.len());
+                }
+                ConfigUpdate::UpgradeRegistry(registry) => {
+                    save_upgrade_registry(&env, &Some(registry.clone()));
+                    env.events()
+                        .publish(("Multisig", "Update upgrade registry"), registry);
+                }
+                ConfigUpdate::RemoveUpgradeRegistry => {
+                    save_upgrade_registry(&env, &None);
+                    env.events()
+                        .publish(("Multisig", "Remove upgrade registry"), ());
+                }
+                ConfigUpdate::RollbackQuorumBps(quorum_bps) => {
+                    save_rollback_quorum_bps(&env, quorum_bps);
+                    env.events()
+                        .publish(("Multisig", "Update rollback quorum"), quorum_bps);
+                }
+                ConfigUpdate::Budget(budget) => {
+                    let mut budgets = get_budgets(&env);
+                    budgets.set(budget.token.clone(), budget.clone());
+                    save_budgets(&env, &budgets);
+                    env.events().publish(("Multisig", "Update budget"), budget);
+                }
+                ConfigUpdate::RemoveBudget(token) => {
+                    let mut budgets = get_budgets(&env);
+                    budgets.remove(token.clone());
+                    save_budgets(&env, &budgets);
+                    env.events().publish(("Multisig", "Remove budget"), token);
+                }
+                ConfigUpdate::BudgetExceedingQuorumBps(quorum_bps) => {
+                    save_budget_exceeding_quorum_bps(&env, quorum_bps);
+                    env.events()
+                        .publish(("Multisig", "Update budget exceeding quorum"), quorum_bps);
+                }
+                ConfigUpdate::StrictSequence(strict_sequence) => {
+                    // the cursor is only kept while the mode is on, it restarts from
+                    // the first proposal when the mode is turned on again
+                    if !strict_sequence {
+                        remove_unsettled_proposal_cursor(&env);
+                    }
+                    save_strict_sequence(&env, strict_sequence);
+                    env.events()
+                        .publish(("Multisig", "Update strict sequence"), strict_sequence);
+                }
+                ConfigUpdate::TrackToken(token) => {
+                    let mut tracked_tokens = get_tracked_tokens(&env);
+                    // tracking a token again keeps its totals
+                    if !tracked_tokens.contains_key(token.clone()) {
+                        tracked_tokens.set(token.clone(), TokenFlows::default());
+                        save_tracked_tokens(&env, &tracked_tokens);
+                    }
+                    env.events().publish(("Multisig", "Track token"), token);
+                }
+                ConfigUpdate::UntrackToken(token) => {
+                    let mut tracked_tokens = get_tracked_tokens(&env);
+                    tracked_tokens.remove(token.clone());
+                    save_tracked_tokens(&env, &tracked_tokens);
+                    env.events().publish(("Multisig", "Untrack token"), token);
+                }
+            },
+            ProposalType::UpdateAddressBook(update) => {
+                let mut address_book = get_address_book(&env);
+                match update {
+                    AddressBookUpdate::Add(entry) => {
+                        address_book
+                            .set((entry.address.clone(), entry.kind.clone()), entry.clone());
+                        env.events()
+                            .publish(("Multisig", "Address book add"), entry);
+                    }
+                    AddressBookUpdate::Remove(address, kind) => {
+                        address_book.remove((address.clone(), kind.clone()));
+                        env.events()
+                            .publish(("Multisig", "Address book remove"), (address, kind));
+                    }
+                    AddressBookUpdate::SetStrictMode(strict_mode) => {
+                        save_address_book_strict_mode(&env, strict_mode);
+                        env.events()
+                            .publish(("Multisig", "Address book strict mode"), strict_mode);
+                    }
+                }
+                save_address_book(&env, &address_book);
+            }
+            ProposalType::UpdateBlocklist(update) => {
+                let mut blocklist = get_blocklist(&env);
+                match update {
+                    BlocklistUpdate::Add(address) => {
+                        blocklist.set(address.clone(), ());
+                        env.events().publish(("Multisig", "Blocklist add"), address);
+                    }
+                    BlocklistUpdate::Remove(address) => {
+                        blocklist.remove(address.clone());
+                        env.events()
+                            .publish(("Multisig", "Blocklist remove"), address);
+                    }
+                }
+                save_blocklist(&env, &blocklist);
+            }
+        }
+
+        // after proposal is executed, mark it as closed
+        close_proposal(&env, &mut proposal, ProposalStatus::Closed);
+
+        env.events()
+            .publish(("Multisig", "Execute proposal ID: "), proposal_id);
+        env.events()
+            .publish(("Multisig", "Execute proposal sender"), sender);
+
+        Ok(())
+    }
+
+    /// Replaces the title and the description of a proposal and, if given, the recipient
+    /// and the amount of a transaction proposal and the expiration date, which counts
+    /// from the creation of the proposal like on creation. Only the creator can amend
+    /// the proposal, before anyone signed it. Every amendment bumps the proposal's
+    /// amendment counter, which is published in the event.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn amend_proposal(
+        env: Env,
+        sender: Address,
+        proposal_id: u64,
+        title: String,
+        description: String,
+        recipient: Option<Address>,
+        amount: Option<i128>,
+        expiration_date: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        let mut proposal = get_draft_proposal(&env, &sender, proposal_id)?;
+        // a new expiration date must not revive a proposal that already expired
+        if env.ledger().timestamp() > proposal.expiration_timestamp {
+            log!(
+                &env,
+                "Multisig: Amend proposal: Trying to amend an expired proposal!"
+            );
+            return Err(ContractError::ProposalExpired);
+        }
+        verify_title_and_description(&env, &title, &description)?;
+        let previous_reservation = get_proposal_reservation(&proposal.proposal);
+        let previous_expiration_timestamp = proposal.expiration_timestamp;
+        proposal.title = title;
+        proposal.description = description;
+
+        let mut reserved_token = None;
+        if recipient.is_some() || amount.is_some() {
+            let ProposalType::Transaction(mut transaction) = proposal.proposal.clone() else {
+                log!(
+                    &env,
+                    "Multisig: Amend proposal: Only transactions have a recipient and an amount!"
+                );
+                return Err(ContractError::InvalidAmendment);
+            };
+            if let Some(recipient) = recipient {
+                verify_not_blocked(&env, &recipient)?;
+                verify_address_book(&env, &recipient, &transaction.token)?;
+                transaction.recipient = recipient;
+            }
+            if let Some(amount) = amount {
+                if amount <= 0 {
+                    log!(&env, "Multisig: Amend proposal: Amount has to be positive!");
+                    return Err(ContractError::InvalidAmount);
+                }
+                transaction.amount = amount;
+            }
+            reserved_token = Some(transaction.token.clone());
+            proposal.proposal = ProposalType::Transaction(transaction);
+        }
+
+        if let Some(expiration_date) = expiration_date {
+            let expiration_timestamp = proposal.creation_timestamp + expiration_date;
+            if expiration_timestamp < env.ledger().timestamp() + ONE_HOUR {
+                log!(
+                    &env,
+                    "Multisig: Amend proposal: Expiration date cannot be less than an hour from now."
+                );
+                return Err(ContractError::InvalidExpirationDate);
+            }
+            if proposal
+                .not_before
+                .is_some_and(|not_before| not_before > expiration_timestamp)
+            {
+                log!(
+                    &env,
+                    "Multisig: Amend proposal: The proposal would expire before it can be executed!"
+                );
+                return Err(ContractError::InvalidNotBefore);
+            }
+            proposal.expiration_timestamp = expiration_timestamp;
+        }
+
+        save_proposal(&env, &proposal);
+        if let Some((token, amount)) = previous_reservation {
+            update_reservation(&env, &token, previous_expiration_timestamp, -amount);
+        }
+        if let Some((token, amount)) = get_proposal_reservation(&proposal.proposal) {
+            update_reservation(&env, &token, proposal.expiration_timestamp, amount);
+        }
+
+        // a larger amount can't take funds reserved by other proposals
+        if let Some(token) = reserved_token {
+            let balance =
+                token_contract::Client::new(&env, &token).balance(&env.current_contract_address());
+            if balance < get_reserved_amount(&env, &token) {
+                log!(
+                    &env,
+                    "Multisig: Amend proposal: Not enough unreserved funds!"
+                );
+                return Err(ContractError::InsufficientBalance);
+            }
+        }
+
+        let amendment = increment_amendments(&env, proposal_id);
+        env.events()
+            .publish(("Multisig", "Amend proposal"), (proposal_id, amendment));
+
+        Ok(())
+    }
+
+    /// Marks a transaction or approve proposal as budget-exceeding, so it can go over the budget
+    /// of its token once it reaches the budget-exceeding quorum. Only the creator can
+    /// change it, before anyone signed the proposal.
+    #[allow(dead_code)]
+    pub fn set_budget_exceeding(
+        env: Env,
+        sender: Address,
+        proposal_id: u64,
+        budget_exceeding: bool,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        let proposal = get_draft_proposal(&env, &sender, proposal_id)?;
+        if !matches!(
+            proposal.proposal,
+            ProposalType::Transaction(_) | ProposalType::Approve(_)
+        ) {
+            log!(
+                &env,
+                "Multisig: Set budget exceeding: Only transactions and allowances spend a budget!"
+            );
+            return Err(ContractError::InvalidAmendment);
+        }
+        save_budget_exceeding(&env, proposal_id, budget_exceeding);
+
+        env.events().publish(
+            ("Multisig", "Set budget exceeding"),
+            (proposal_id, budget_exceeding),
+        );
+
+        Ok(())
+    }
+
+    /// Makes a transaction proposal claimable. Executing it then doesn't transfer the
+    /// tokens, the recipient withdraws them with `claim` within `claim_window` seconds
+    /// after the execution. `None` turns it back into a regular transaction. Only the
+    /// creator can change it, before anyone signed the proposal.
+    #[allow(dead_code)]
+    pub fn set_claimable(
+        env: Env,
+        sender: Address,
+        proposal_id: u64,
+        claim_window: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        let proposal = get_draft_proposal(&env, &sender, proposal_id)?;
+        if !matches!(proposal.proposal, ProposalType::Transaction(_)) {
+            log!(
+                &env,
+                "Multisig: Set claimable: Only transactions can be made claimable!"
+            );
+            return Err(ContractError::InvalidAmendment);
+        }
+        // the deadline is computed on execution, at the latest at the expiration
+        if let Some(claim_window) = claim_window {
+            if claim_window == 0
+                || proposal
+                    .expiration_timestamp
+                    .checked_add(claim_window)
+                    .is_none()
+            {
+                log!(
+                    &env,
+                    "Multisig: Set claimable: Claim window has to be positive and end in time!"
+                );
+                return Err(ContractError::InvalidExpirationDate);
+            }
+        }
+        save_claim_window(&env, proposal_id, claim_window);
+
+        env.events()
+            .publish(("Multisig", "Set claimable"), (proposal_id, claim_window));
+
+        Ok(())
+    }
+
+    /// Sets a condition that has to hold when the proposal is executed, e.g. an oracle
+    /// price below a bound. `None` removes it. Only the creator can change it, before
+    /// anyone signed the proposal.
+    #[allow(dead_code)]
+    pub fn set_execution_condition(
+        env: Env,
+        sender: Address,
+        proposal_id: u64,
+        condition: Option<ExecutionCondition>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        get_draft_proposal(&env, &sender, proposal_id)?;
+        if let Some(ExecutionCondition::ViewCall(call)) = &condition {
+            verify_view_call_target(&env, &call.contract)?;
+        }
+        save_execution_condition(&env, proposal_id, &condition);
+
+        env.events().publish(
+            ("Multisig", "Set execution condition"),
+            (proposal_id, condition),
+        );
+
+        Ok(())
+    }
+
+    /// Sets the earliest timestamp the proposal can be executed at, so signatures can
+    /// be collected ahead of time. `None` removes it. Only the creator can change it,
+    /// before anyone signed the proposal.
+    #[allow(dead_code)]
+    pub fn set_not_before(
+        env: Env,
+        sender: Address,
+        proposal_id: u64,
+        not_before: Option<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        let mut proposal = get_draft_proposal(&env, &sender, proposal_id)?;
+        if not_before.is_some_and(|not_before| not_before > proposal.expiration_timestamp) {
+            log!(
+                &env,
+                "Multisig: Set not before: The proposal would expire before it can be executed!"
+            );
+            return Err(ContractError::InvalidNotBefore);
+        }
+
+        proposal.not_before = not_before;
+        save_proposal(&env, &proposal);
+
+        env.events()
+            .publish(("Multisig", "Set not before"), (proposal_id, not_before));
+
+        Ok(())
+    }
+
+    /// Sets the proposals that have to be executed before this one. If any of them gets
+    /// rejected or cancelled, this proposal is cancelled as well. Only the creator can
+    /// change it, before anyone signed the proposal.
+    #[allow(dead_code)]
+    pub fn set_dependencies(
+        env: Env,
+        sender: Address,
+        proposal_id: u64,
+        dependencies: Vec<u64>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        get_draft_proposal(&env, &sender, proposal_id)?;
+
+        for dependency_id in dependencies.iter() {
+            // only earlier proposals, so dependencies can't form a cycle
+            let is_valid = dependency_id < proposal_id
+                && get_proposal(&env, dependency_id).is_some_and(|dependency| {
+                    dependency.status == ProposalStatus::Open
+                        || dependency.status == ProposalStatus::Closed
+                });
+            if !is_valid {
+                log!(
+                    &env,
+                    "Multisig: Set dependencies: Dependency has to be an earlier proposal that wasn't rejected or cancelled!"
+                );
+                return Err(ContractError::InvalidDependency);
+            }
+        }
+
+        for dependency_id in get_dependencies(&env, proposal_id).iter() {
+            let mut dependents = get_dependents(&env, dependency_id);
+            if let Some(index) = dependents.first_index_of(proposal_id) {
+                dependents.remove(index);
+            }
+            save_dependents(&env, dependency_id, &dependents);
+        }
+        for dependency_id in dependencies.iter() {
+            let mut dependents = get_dependents(&env, dependency_id);
+            if !dependents.contains(proposal_id) {
+                dependents.push_back(proposal_id);
+            }
+            save_dependents(&env, dependency_id, &dependents);
+        }
+        save_dependencies(&env, proposal_id, &dependencies);
+
+        env.events().publish(
+            ("Multisig", "Set dependencies"),
+            (proposal_id, dependencies),
+        );
+
+        Ok(())
+    }
+
+    /// Withdraws an open proposal. Only the creator can cancel it. Proposals that
+    /// depend on it are cancelled as well.
+    #[allow(dead_code)]
+    pub fn cancel_proposal(
+        env: Env,
+        sender: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        let mut proposal =
+            get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        if proposal.sender != sender {
+            log!(
+                &env,
+                "Multisig: Cancel proposal: Only the creator can cancel the proposal!"
+            );
+            return Err(ContractError::Unauthorized);
+        }
+        if proposal.status != ProposalStatus::Open {
+            log!(
+                &env,
+                "Multisig: Cancel proposal: Trying to cancel a closed proposal!"
+            );
+            return Err(ContractError::ProposalClosed);
+        }
+
+        close_proposal(&env, &mut proposal, ProposalStatus::Cancelled);
+        cancel_dependents(&env, proposal_id);
+
+        env.events()
+            .publish(("Multisig", "Cancel proposal ID: "), proposal_id);
+        env.events()
+            .publish(("Multisig", "Cancel proposal sender"), sender);
+
+        Ok(())
+    }
+
+    /// Closes an open proposal right away. Only veto holders can reject proposals.
+    #[allow(dead_code)]
+    pub fn reject_proposal(
+        env: Env,
+        sender: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        let mut proposal = match get_proposal(&env, proposal_id) {
+            Some(proposal) => proposal,
+            None => {
+                log!(
+                    &env,
+                    "Multisig: Reject proposal: Proposal with this ID does not exist!"
+                );
+                return Err(ContractError::ProposalNotFound);
+            }
+        };
+
+        let multisig = get_multisig_members(&env);
+        if !multisig.contains_key(sender.clone())
+            || !get_signer_roles(&env).veto_holders.contains(sender.clone())
+        {
+            log!(
+                &env,
+                "Multisig: Reject proposal: Sender is not a veto holder of this multisig!"
+            );
+            return Err(ContractError::Unauthorized);
+        }
+
+        if proposal.status != ProposalStatus::Open {
+            log!(
+                &env,
+                "Multisig: Reject proposal: Trying to reject a closed proposal!"
+            );
+            return Err(ContractError::ProposalClosed);
+        }
+
+        close_proposal(&env, &mut proposal, ProposalStatus::Rejected);
+        cancel_dependents(&env, proposal_id);
+
+        env.events()
+            .publish(("Multisig", "Reject proposal ID: "), proposal_id);
+        env.events()
+            .publish(("Multisig", "Reject proposal sender"), sender);
+
+        Ok(())
+    }
+
+    /// Migrates the storage to the layout of the currently running code. Soroban doesn't
+    /// allow a contract to call itself, so this can't be done in the same invocation as
+    /// the upgrade. Any member can call it once an executed upgrade proposal installed
+    /// code with a newer storage version, `from_version` has to be the current one.
+    /// Each call converts up to `limit` proposals, so the migration of a long history
+    /// stays within the ledger limits of a transaction. Returns true once it's complete.
+    #[allow(dead_code)]
+    pub fn migrate(
+        env: Env,
+        sender: Address,
+        from_version: u32,
+        limit: u32,
+    ) -> Result<bool, ContractError> {
+        sender.require_auth();
+
+        if !get_multisig_members(&env).contains_key(sender) {
+            log!(
+                &env,
+                "Multisig: Migrate: Sender is not a member of this multisig!"
+            );
+            return Err(ContractError::UnauthorizedNotAMember);
+        }
+
+        let storage_version = get_storage_version(&env);
+        if storage_version >= STORAGE_VERSION {
+            log!(
+                &env,
+                "Multisig: Migrate: The storage is already up to date!"
+            );
+            return Err(ContractError::MigrationNotScheduled);
+        }
+        if from_version != storage_version {
+            log!(
+                &env,
+                "Multisig: Migrate: Migration has to start from the current storage version!"
+            );
+            return Err(ContractError::InvalidMigrationVersion);
+        }
+
+        let mut progress = get_migration_progress(&env).unwrap_or(MigrationProgress {
+            next_proposal_id: 1,
+            last_proposal_id: get_last_proposal_id(&env),
+        });
+        // version 3 keeps reserved funds as running totals, which are seeded here
+        let seed_reservations = from_version < 3;
+        let mut migrated = 0u32;
+        while migrated < limit && progress.next_proposal_id <= progress.last_proposal_id {
+            migrate_proposal(&env, progress.next_proposal_id, from_version);
+            if seed_reservations {
+                seed_proposal_reservations(&env, progress.next_proposal_id);
+            }
+            progress.next_proposal_id += 1;
+            migrated += 1;
+        }
+
+        if progress.next_proposal_id <= progress.last_proposal_id {
+            save_migration_progress(&env, &Some(progress));
+            return Ok(false);
+        }
+        save_migration_progress(&env, &None);
+        save_storage_version(&env, STORAGE_VERSION);
+
+        // the code before the upgrade can't read the migrated storage
+        let mut history = get_upgrade_history(&env);
+        if let Some(mut latest_upgrade) = history.last() {
+            latest_upgrade.with_migration = true;
+            history.set(history.len() - 1, latest_upgrade);
+            save_upgrade_history(&env, &history);
+        }
+
+        env.events()
+            .publish(("Multisig", "Migrate"), (from_version, STORAGE_VERSION));
+
+        Ok(true)
+    }
+
+    // ----------- QUERY
+
+    /// Steps over up to `limit` settled proposals in strict sequence mode, so a long
+    /// history of settled proposals doesn't block the execution of the next one. Any
+    /// member can call it. Returns the lowest proposal ID that may still be open.
+    #[allow(dead_code)]
+    pub fn advance_sequence(env: Env, sender: Address, limit: u32) -> Result<u64, ContractError> {
+        sender.require_auth();
+
+        if !get_multisig_members(&env).contains_key(sender) {
+            log!(
+                &env,
+                "Multisig: Advance sequence: Sender is not a member of this multisig!"
+            );
+            return Err(ContractError::UnauthorizedNotAMember);
+        }
+        // without strict sequence mode no earlier proposal holds back the next one
+        let next_proposal_id = get_last_proposal_id(&env) + 1;
+        if !is_strict_sequence(&env) {
+            return Ok(next_proposal_id);
+        }
+
+        Ok(advance_unsettled_proposal_cursor(
+            &env,
+            next_proposal_id,
+            limit,
+        ))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_multisig_info(env: Env) -> Result<MultisigInfo, ContractError> {
+        let (name, description) = get_name(&env);
+        Ok(MultisigInfo {
+            name: name.clone(),
+            description,
+            members: get_multisig_members(&env).keys(),
+            quorum_bps: get_quorum_bps(&env),
+            version_proposal: get_version(&env),
+            upgrade_history: get_upgrade_history(&env),
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn query_storage_version(env: Env) -> Result<u32, ContractError> {
+        Ok(get_storage_version(&env))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_amendments(env: Env, proposal_id: u64) -> Result<u32, ContractError> {
+        Ok(get_amendments(&env, proposal_id))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_execution_condition(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<Option<ExecutionCondition>, ContractError> {
+        Ok(get_execution_condition(&env, proposal_id))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_dependencies(env: Env, proposal_id: u64) -> Result<Vec<u64>, ContractError> {
+        Ok(get_dependencies(&env, proposal_id))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_strict_sequence(env: Env) -> Result<bool, ContractError> {
+        Ok(is_strict_sequence(&env))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_multisig_members(env: Env) -> Result<Vec<Address>, ContractError> {
+        let multisig_members = get_multisig_members(&env).keys();
+        Ok(multisig_members)
+    }
+
+    #[allow(dead_code)]
+    pub fn query_proposal(env: Env, proposal_id: u64) -> Result<Proposal, ContractError> {
+        get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)
+    }
+
+    /// Returns every member together with the information if it signed the proposal
+    /// and, when the signature was made by a delegate, the delegate's address
+    #[allow(dead_code)]
+    pub fn query_signatures(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<Vec<(Address, bool, Option<Address>)>, ContractError> {
+        let multisig = get_multisig_members(&env);
+        // collect all addresses that signed this proposal
+        let proposal_signatures = get_proposal_signatures(&env, proposal_id);
+
+        let mut response: Vec<(Address, bool, Option<Address>)> = vec![&env];
+
+        for (member, _) in multisig {
+            match proposal_signatures.get(member.clone()) {
+                Some(signer) if signer == member => response.push_back((member, true, None)),
+                Some(signer) => response.push_back((member, true, Some(signer))),
+                None => response.push_back((member, false, None)),
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Returns all delegations that are still valid
+    #[allow(dead_code)]
+    pub fn query_delegations(env: Env) -> Result<Vec<Delegation>, ContractError> {
+        let curr_timestamp = env.ledger().timestamp();
+        let mut delegations: Vec<Delegation> = vec![&env];
+        for delegation in get_delegations(&env).values() {
+            if delegation.expiration_timestamp >= curr_timestamp {
+                delegations.push_back(delegation);
+            }
+        }
+
+        Ok(delegations)
+    }
+
+    #[allow(dead_code)]
+    pub fn query_last_proposal_id(env: Env) -> Result<u64, ContractError> {
+        let last_id = get_last_proposal_id(&env);
+        Ok(last_id)
+    }
+
+    #[allow(dead_code)]
+    pub fn query_all_proposals(env: Env) -> Result<Vec<Proposal>, ContractError> {
+        let last_proposal_id = get_last_proposal_id(&env);
+        let mut proposals: Vec<Proposal> = vec![&env];
+
+        // I think get_proposal should return the option, so that this won't fail in case
+        // of deleted proposal
+        for i in 1..=last_proposal_id {
+            get_proposal(&env, i).is_some().then(|| {
+                let current_prosal = get_proposal(&env, i).unwrap();
+                proposals.push_back(current_prosal);
+            });
+        }
+
+        Ok(proposals)
+    }
+
+    #[allow(dead_code)]
+    pub fn query_signer_roles(env: Env) -> Result<SignerRoles, ContractError> {
+        Ok(get_signer_roles(&env))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_address_book(env: Env) -> Result<Vec<AddressBookEntry>, ContractError> {
+        Ok(get_address_book(&env).values())
+    }
+
+    #[allow(dead_code)]
+    pub fn query_address_book_strict_mode(env: Env) -> Result<bool, ContractError> {
+        Ok(is_address_book_strict(&env))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_blocklist(env: Env) -> Result<Vec<Address>, ContractError> {
+        Ok(get_blocklist(&env).keys())
+    }
+
+    #[allow(dead_code)]
+    pub fn query_upgrade_registry(env: Env) -> Result<Option<Address>, ContractError> {
+        Ok(get_upgrade_registry(&env))
+    }
+
+    /// Checks that `wasm_hash` can be installed and exposes the entrypoints the multisig
+    /// needs. Meant to be simulated before proposing the upgrade, it deploys a probe of
+    /// the new code.
+    #[allow(dead_code)]
+    pub fn query_wasm_compatibility(env: Env, wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        verify_wasm_hash_published(&env, &wasm_hash)?;
+        verify_wasm_compatible(&env, &wasm_hash)
+    }
+
+    #[allow(dead_code)]
+    pub fn query_deposit(env: Env, deposit_id: u64) -> Result<Deposit, ContractError> {
+        get_deposit(&env, deposit_id).ok_or(ContractError::DepositNotFound)
+    }
+
+    #[allow(dead_code)]
+    pub fn query_claim(env: Env, proposal_id: u64) -> Result<Claim, ContractError> {
+        get_claim(&env, proposal_id).ok_or(ContractError::ClaimNotFound)
+    }
+
+    /// Returns the claim window of a claimable transaction proposal, `None` if it
+    /// transfers the tokens on execution
+    #[allow(dead_code)]
+    pub fn query_claim_window(env: Env, proposal_id: u64) -> Result<Option<u64>, ContractError> {
+        Ok(get_claim_window(&env, proposal_id))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_last_deposit_id(env: Env) -> Result<u64, ContractError> {
+        Ok(get_last_deposit_id(&env))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_stream(env: Env, stream_id: u64) -> Result<Stream, ContractError> {
+        get_stream(&env, stream_id).ok_or(ContractError::StreamNotFound)
+    }
+
+    #[allow(dead_code)]
+    pub fn query_last_stream_id(env: Env) -> Result<u64, ContractError> {
+        Ok(get_last_stream_id(&env))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_vesting(env: Env, vesting_id: u64) -> Result<Vesting, ContractError> {
+        get_vesting(&env, vesting_id).ok_or(ContractError::VestingNotFound)
+    }
+
+    #[allow(dead_code)]
+    pub fn query_last_vesting_id(env: Env) -> Result<u64, ContractError> {
+        Ok(get_last_vesting_id(&env))
+    }
+
+    /// Returns every budget with its spending in the current period
+    #[allow(dead_code)]
+    pub fn query_budgets(env: Env) -> Result<Vec<BudgetStatus>, ContractError> {
+        let usage = get_budget_usage(&env);
+        let mut budgets: Vec<BudgetStatus> = vec![&env];
+        for (token, budget) in get_budgets(&env) {
+            let period_start = current_period_start(&env, &budget.period);
+            let spent = match usage.get(token) {
+                Some(usage) if usage.period_start == period_start => usage.spent,
+                _ => 0,
+            };
+            budgets.push_back(BudgetStatus {
+                remaining: budget.amount - spent,
+                budget,
+                period_start,
+                spent,
+            });
+        }
+
+        Ok(budgets)
+    }
+
+    /// Returns whether the proposal is marked as budget-exceeding and needs the
+    /// budget-exceeding quorum
+    #[allow(dead_code)]
+    pub fn query_budget_exceeding(env: Env, proposal_id: u64) -> Result<bool, ContractError> {
+        Ok(is_budget_exceeding(&env, proposal_id))
+    }
+
+    #[allow(dead_code)]
+    pub fn query_tracked_tokens(env: Env) -> Result<Vec<Address>, ContractError> {
+        Ok(get_tracked_tokens(&env).keys())
+    }
+
+    /// Returns the balance and the inflow and outflow totals of each token,
+    /// an empty vector queries the tokens tracked by config proposals
+    #[allow(dead_code)]
+    pub fn query_treasury(
+        env: Env,
+        tokens: Vec<Address>,
+    ) -> Result<Vec<TreasuryBalance>, ContractError> {
+        let tracked_tokens = get_tracked_tokens(&env);
+        let tokens = if tokens.is_empty() {
+            tracked_tokens.keys()
+        } else {
+            tokens
+        };
+
+        let mut treasury: Vec<TreasuryBalance> = vec![&env];
+        for token in tokens.iter() {
+            let flows = tracked_tokens.get(token.clone()).unwrap_or_default();
+            let balance =
+                token_contract::Client::new(&env, &token).balance(&env.current_contract_address());
+            let reserved = get_reserved_amount(&env, &token);
+            treasury.push_back(TreasuryBalance {
+                token,
+                balance,
+                reserved,
+                available: balance - reserved,
+                total_inflow: flows.total_inflow,
+                total_outflow: flows.total_outflow,
+            });
+        }
+
+        Ok(treasury)
+    }
+
+    /// Returns whether the proposal reached its quorum, its earliest execution time and
+    /// has all of its dependencies executed
+    #[allow(dead_code)]
+    pub fn is_proposal_ready(env: Env, proposal_id: u64) -> Result<bool, ContractError> {
+        let proposal = get_proposal(&env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+        let is_due = proposal
+            .not_before
+            .map_or(true, |not_before| env.ledger().timestamp() >= not_before);
+
+        Ok(is_due
+            && verify_dependencies_executed(&env, proposal_id).is_ok()
+            && check_quorum(&env, &proposal).is_ok())
+    }
+}
+
+// Validates and stores a new proposal, returns the ID it was saved under
+fn create_proposal(
+    env: &Env,
+    sender: Address,
+    title: String,
+    description: String,
+    proposal: ProposalType,
+    expiration_date: Option<u64>,
+) -> Result<u64, ContractError> {
+    let multisig = get_multisig_members(env);
+
+    // check if sender is a member of this multisig
+    if multisig.get(sender.clone()).is_none() {
+        log!(
+            env,
+            "Multisig: Create proposal: Sender is not a member of this multisig!"
+        );
+        return Err(ContractError::UnauthorizedNotAMember);
+    }
+
+    verify_title_and_description(env, &title, &description)?;
+
+    // loads the previous id, returns it and increments before saving
+    let proposal_id = increment_last_proposal_id(env);
+
+    let creation_timestamp = env.ledger().timestamp();
+    let expiration_timestamp = creation_timestamp
+        + expiration_date.unwrap_or(creation_timestamp + SEVEN_DAYS_EXPIRATION_DATE);
+    if expiration_timestamp < creation_timestamp + ONE_HOUR {
+        log!(
+            env,
+            "Multisig: Create proposal: Expiration date cannot be less than an hour."
+        );
+        return Err(ContractError::InvalidExpirationDate);
+    }
+
+    // rollbacks skip the approval workflow to revert a broken release quickly
+    let stages = match proposal {
+        ProposalType::Rollback(_) => vec![env],
+        _ => get_approval_stages(env),
+    };
+
+    let proposal = Proposal {
+        id: proposal_id,
+        sender: sender.clone(),
+        proposal,
+        status: ProposalStatus::Open,
+        creation_timestamp,
+        expiration_timestamp,
+        title: title.clone(),
+        description,
+        stages,
+        current_stage: 0,
+        not_before: None,
+    };
+
+    save_proposal(env, &proposal);
+    if let Some((token, amount)) = get_proposal_reservation(&proposal.proposal) {
+        update_reservation(env, &token, expiration_timestamp, amount);
+    }
+
+    env.events()
+        .publish(("Multisig", "Create proposal Title"), title);
+    env.events()
+        .publish(("Multisig", "Create proposal Sender"), sender);
+
+    Ok(proposal_id)
+}
+
+// Checks if the signatures collected for the proposal satisfy the quorum
+// (or all of its approval stages) and that every mandatory signer is among them
+fn check_quorum(env: &Env, proposal: &Proposal) -> Result<(), ContractError> {
+    // collect all addresses that signed this proposal
+    let proposal_signatures = get_proposal_signatures(env, proposal.id);
+    let multisig = get_multisig_members(env);
+
+    if proposal.stages.is_empty() {
+        let multisig_len = multisig.len();
+        let mut signed = 0u32;
+        for member in multisig.keys() {
+            if proposal_signatures.get(member).is_some() {
+                signed += 1;
+            }
+        }
+
+        // get required quorum and compare it with ratio of vote confirmations vs multisig len
+        let quorum_bps = match proposal.proposal {
+            ProposalType::Rollback(_) => {
+                get_rollback_quorum_bps(env).unwrap_or_else(|| get_quorum_bps(env))
+            }
+            _ => get_quorum_bps(env),
+        };
+        let required_quorum = Decimal::bps(quorum_bps as i64);
+        let voted_ratio = Decimal::from_ratio(signed, multisig_len);
+        if voted_ratio < required_quorum {
+            log!(
+                env,
+                "Multisig: Check quorum: Required quorum has not been reached!"
+            );
+            return Err(ContractError::QuorumNotReached);
+        }
+    } else if proposal.current_stage < proposal.stages.len() {
+        log!(
+            env,
+            "Multisig: Check quorum: Not all approval stages have been passed!"
+        );
+        return Err(ContractError::QuorumNotReached);
+    }
+
+    // budget-exceeding proposals need the higher quorum on top of everything else
+    if is_budget_exceeding(env, proposal.id) {
+        let mut signed = 0u32;
+        for member in multisig.keys() {
+            if proposal_signatures.contains_key(member) {
+                signed += 1;
+            }
+        }
+        let required_quorum = Decimal::bps(get_budget_exceeding_quorum_bps(env) as i64);
+        if Decimal::from_ratio(signed, multisig.len()) < required_quorum {
+            log!(
+                env,
+                "Multisig: Check quorum: Budget-exceeding quorum has not been reached!"
+            );
+            return Err(ContractError::QuorumNotReached);
+        }
+    }
+
+    for mandatory_signer in get_signer_roles(env).mandatory_signers {
+        if multisig.contains_key(mandatory_signer.clone())
+            && !proposal_signatures.contains_key(mandatory_signer)
+        {
+            log!(
+                env,
+                "Multisig: Check quorum: Mandatory signer has not signed the proposal!"
+            );
+            return Err(ContractError::MandatorySignatureMissing);
+        }
+    }
+
+    Ok(())
+}
+
+// Funds of the token promised by open proposals, claims, streams, vestings, swap escrows
+// and allowances. Reservations of expired proposals and claims don't count anymore.
+fn get_reserved_amount(env: &Env, token: &Address) -> i128 {
+    let curr_timestamp = env.ledger().timestamp();
+    let mut reserved = 0i128;
+    for (until, amount) in get_reservations(env, token).iter() {
+        if until >= curr_timestamp {
+            reserved += amount;
+        }
+    }
+
+    // the token tracks what is left of an allowance and drops it once it expires
+    let spenders = get_spenders(env, token);
+    if !spenders.is_empty() {
+        let token = token_contract::Client::new(env, token);
+        for spender in spenders.iter() {
+            reserved += token.allowance(&env.current_contract_address(), &spender);
+        }
+    }
+
+    reserved
+}
+
+// Adds to the funds of the token reserved until the given timestamp, a negative amount
+// releases them. Expired reservations are dropped on the way, releasing one is a no-op.
+fn update_reservation(env: &Env, token: &Address, until: u64, amount: i128) {
+    let curr_timestamp = env.ledger().timestamp();
+    let mut reservations = get_reservations(env, token);
+    // keys are sorted, so the expired reservations come first
+    for expired_until in reservations.keys().iter() {
+        if expired_until >= curr_timestamp {
+            break;
+        }
+        reservations.remove(expired_until);
+    }
+
+    if until >= curr_timestamp {
+        let reserved = reservations.get(until).unwrap_or(0) + amount;
+        if reserved == 0 {
+            reservations.remove(until);
+        } else {
+            reservations.set(until, reserved);
+        }
+    }
+    save_reservations(env, token, &reservations);
+}
+
+// Token and amount an open proposal reserves until it expires
+fn get_proposal_reservation(proposal: &ProposalType) -> Option<(Address, i128)> {
+    match proposal {
+        ProposalType::Transaction(t) => Some((t.token.clone(), t.amount)),
+        ProposalType::Burn(burn) => Some((burn.token.clone(), burn.amount)),
+        ProposalType::Swap(swap) => Some((swap.send_token.clone(), swap.send_amount)),
+        ProposalType::Approve(allowance) => Some((allowance.token.clone(), allowance.amount)),
+        ProposalType::Stream(StreamAction::Create(terms)) => Some((
+            terms.token.clone(),
+            terms.amount_per_period
+                * ((terms.end_timestamp - terms.start_timestamp) / terms.period) as i128,
+        )),
+        ProposalType::Vesting(VestingAction::Create(terms)) => {
+            Some((terms.token.clone(), terms.amount))
+        }
+        _ => None,
+    }
+}
+
+// Unpaid remainder of a stream
+fn get_stream_reservation(stream: &Stream) -> i128 {
+    stream.carried_over
+        + stream.amount_per_period
+            * ((stream.end_timestamp - stream.start_timestamp) / stream.period) as i128
+        - stream.claimed
+}
+
+// Unpaid remainder of a vesting, the unvested part of a revoked vesting returns to the treasury
+fn get_vesting_reservation(vesting: &Vesting) -> i128 {
+    let total = match vesting.revoked_timestamp {
+        Some(revoked_timestamp) => get_vested_amount(vesting, revoked_timestamp),
+        None => vesting.amount,
+    };
+
+    total - vesting.claimed
+}
+
+// Settles an open proposal with the given status and releases the funds it reserved
+fn close_proposal(env: &Env, proposal: &mut Proposal, status: ProposalStatus) {
+    proposal.status = status;
+    save_proposal(env, proposal);
+    if let Some((token, amount)) = get_proposal_reservation(&proposal.proposal) {
+        update_reservation(env, &token, proposal.expiration_timestamp, -amount);
+    }
+    if is_strict_sequence(env) && proposal.id == get_unsettled_proposal_cursor(env) {
+        advance_unsettled_proposal_cursor(env, get_last_proposal_id(env) + 1, MAX_SEQUENCE_STEPS);
+    }
+}
+
+// Moves the cursor past settled proposals, at most `max_steps` of them and not beyond
+// `limit`, and returns it. Proposals never reopen once they're closed or expired, so
+// each one is only stepped over once.
+fn advance_unsettled_proposal_cursor(env: &Env, limit: u64, max_steps: u32) -> u64 {
+    let curr_timestamp = env.ledger().timestamp();
+    let initial_cursor = get_unsettled_proposal_cursor(env);
+    let mut cursor = initial_cursor;
+    let mut steps = 0u32;
+    while cursor < limit && steps < max_steps {
+        steps += 1;
+        let is_pending = get_proposal(env, cursor).is_some_and(|proposal| {
+            proposal.status == ProposalStatus::Open
+                && curr_timestamp <= proposal.expiration_timestamp
+        });
+        if is_pending {
+            break;
+        }
+        cursor += 1;
+    }
+    if cursor != initial_cursor {
+        save_unsettled_proposal_cursor(env, cursor);
+    }
+    cursor
+}
+
+// Reserves the funds promised by a proposal created before reservations were kept
+// as running totals, used by the migration to storage version 3
+fn seed_proposal_reservations(env: &Env, proposal_id: u64) {
+    let Some(proposal) = get_proposal(env, proposal_id) else {
+        return;
+    };
+
+    if proposal.status == ProposalStatus::Open {
+        if let Some((token, amount)) = get_proposal_reservation(&proposal.proposal) {
+            update_reservation(env, &token, proposal.expiration_timestamp, amount);
+        }
+    }
+}
+
+// Amount of the vesting unlocked at the given timestamp, including the claimed part
+fn get_vested_amount(vesting: &Vesting, timestamp: u64) -> i128 {
+    if timestamp < vesting.cliff_timestamp {
+        return 0;
+    }
+    if timestamp >= vesting.end_timestamp {
+        return vesting.amount;
+    }
+
+    vesting.amount * (timestamp - vesting.start_timestamp) as i128
+        / (vesting.end_timestamp - vesting.start_timestamp) as i128
+}
+
+// Number of full periods of the stream that passed until now or until its end
+fn get_stream_elapsed_periods(env: &Env, stream: &Stream) -> u64 {
+    let until = env.ledger().timestamp().min(stream.end_timestamp);
+    until.saturating_sub(stream.start_timestamp) / stream.period
+}
+
+// Total amount the stream has accrued, including the already claimed part
+fn get_stream_accrued(env: &Env, stream: &Stream) -> i128 {
+    stream.carried_over + stream.amount_per_period * get_stream_elapsed_periods(env, stream) as i128
+}
+
+// Moves the unclaimed amount accrued under the current terms to the carried over amount
+// and restarts the stream at its last full period, so the terms can be changed
+fn settle_stream(env: &Env, stream: &mut Stream) {
+    let elapsed_periods = get_stream_elapsed_periods(env, stream);
+    stream.carried_over = get_stream_accrued(env, stream) - stream.claimed;
+    stream.claimed = 0;
+    stream.start_timestamp += elapsed_periods * stream.period;
+}
+
+// Start of the budget period that contains the current ledger timestamp
+fn current_period_start(env: &Env, period: &BudgetPeriod) -> u64 {
+    let curr_timestamp = env.ledger().timestamp();
+    match period {
+        BudgetPeriod::Seconds(length) => curr_timestamp - curr_timestamp % length,
+        BudgetPeriod::CalendarMonth => {
+            let days = curr_timestamp / ONE_DAY;
+            (days + 1 - day_of_month(days)) * ONE_DAY
+        }
+    }
+}
+
+// Day of the month (1..=31) of a day counted from 1970-01-01, using the civil calendar
+// algorithm of Howard Hinnant with eras of 400 years starting on March 1st
+fn day_of_month(days: u64) -> u64 {
+    let shifted = days + 719_468;
+    let day_of_era = shifted % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    day_of_year - (153 * month + 2) / 5 + 1
+}
+
+// Adds a payment to the spending of the token's budget, fails if it goes over the
+// remaining budget and the proposal isn't marked as budget-exceeding
+fn spend_budget(
+    env: &Env,
+    token: &Address,
+    amount: i128,
+    budget_exceeding: bool,
+) -> Result<(), ContractError> {
+    let Some(budget) = get_budgets(env).get(token.clone()) else {
+        return Ok(());
+    };
+
+    let period_start = current_period_start(env, &budget.period);
+    let mut usage = get_budget_usage(env);
+    let mut token_usage = usage.get(token.clone()).unwrap_or_default();
+    if token_usage.period_start != period_start {
+        token_usage = BudgetUsage {
+            period_start,
+            spent: 0,
+        };
+    }
+
+    if token_usage.spent + amount > budget.amount && !budget_exceeding {
+        log!(
+            env,
+            "Multisig: Execute proposal: Transaction goes over the remaining budget!"
+        );
+        return Err(ContractError::BudgetExceeded);
+    }
+
+    token_usage.spent += amount;
+    usage.set(token.clone(), token_usage);
+    save_budget_usage(env, &usage);
+
+    Ok(())
+}
+
+// Cancels the open proposals that depend on the proposal, and their dependents in turn
+fn cancel_dependents(env: &Env, proposal_id: u64) {
+    for dependent_id in get_dependents(env, proposal_id).iter() {
+        let Some(mut dependent) = get_proposal(env, dependent_id) else {
+            continue;
+        };
+        if dependent.status != ProposalStatus::Open {
+            continue;
+        }
+
+        close_proposal(env, &mut dependent, ProposalStatus::Cancelled);
+        env.events()
+            .publish(("Multisig", "Cancel proposal ID: "), dependent_id);
+        cancel_dependents(env, dependent_id);
+    }
+}
+
+// Every dependency of the proposal has to be executed before it
+fn verify_dependencies_executed(env: &Env, proposal_id: u64) -> Result<(), ContractError> {
+    for dependency_id in get_dependencies(env, proposal_id).iter() {
+        let is_executed = get_proposal(env, dependency_id)
+            .is_some_and(|dependency| dependency.status == ProposalStatus::Closed);
+        if !is_executed {
+            log!(
+                env,
+                "Multisig: Execute proposal: A dependency has not been executed yet!"
+            );
+            return Err(ContractError::DependencyNotExecuted);
+        }
+    }
+
+    Ok(())
+}
+
+// Dependencies have to be executed first and, in strict sequence mode, every earlier
+// proposal has to be settled, i.e. executed, rejected, cancelled or expired
+fn verify_execution_order(env: &Env, proposal_id: u64) -> Result<(), ContractError> {
+    verify_dependencies_executed(env, proposal_id)?;
+
+    if is_strict_sequence(env)
+        && advance_unsettled_proposal_cursor(env, proposal_id, MAX_SEQUENCE_STEPS) < proposal_id
+    {
+        log!(
+            env,
+            "Multisig: Execute proposal: An earlier proposal is still pending or the sequence has to be advanced!"
+        );
+        return Err(ContractError::ProposalOutOfOrder);
+    }
+
+    Ok(())
+}
+
+// Returns an open proposal of the sender that hasn't been signed yet, only such
+// proposals can still be changed by their creator
+fn get_draft_proposal(
+    env: &Env,
+    sender: &Address,
+    proposal_id: u64,
+) -> Result<Proposal, ContractError> {
+    let proposal = get_proposal(env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+
+    if &proposal.sender != sender {
+        log!(
+            env,
+            "Multisig: Update proposal: Only the creator can change the proposal!"
+        );
+        return Err(ContractError::Unauthorized);
+    }
+    if proposal.status != ProposalStatus::Open {
+        log!(
+            env,
+            "Multisig: Update proposal: Trying to change a closed proposal!"
+        );
+        return Err(ContractError::ProposalClosed);
+    }
+    if !get_proposal_signatures(env, proposal_id).is_empty() {
+        log!(
+            env,
+            "Multisig: Update proposal: Signed proposals can't be changed!"
+        );
+        return Err(ContractError::ProposalAlreadySigned);
+    }
+
+    Ok(proposal)
+}
+
+// Adds a received amount to the inflow total of the token, untracked tokens are ignored
+fn record_inflow(env: &Env, token: &Address, amount: i128) {
+    let mut tracked_tokens = get_tracked_tokens(env);
+    let Some(mut flows) = tracked_tokens.get(token.clone()) else {
+        return;
+    };
+    flows.total_inflow += amount;
+    tracked_tokens.set(token.clone(), flows);
+    save_tracked_tokens(env, &tracked_tokens);
+}
+
+// Adds a payment to the outflow total of the token, untracked tokens are ignored
+fn record_outflow(env: &Env, token: &Address, amount: i128) {
+    let mut tracked_tokens = get_tracked_tokens(env);
+    let Some(mut flows) = tracked_tokens.get(token.clone()) else {
+        return;
+    };
+    flows.total_outflow += amount;
+    tracked_tokens.set(token.clone(), flows);
+    save_tracked_tokens(env, &tracked_tokens);
+}
+
+// Returns the wasm hash a rollback would restore
+fn get_rollback_target(env: &Env) -> Result<BytesN<32>, ContractError> {
+    let history = get_upgrade_history(env);
+    let Some(latest_upgrade) = history.last() else {
+        log!(
+            env,
+            "Multisig: Rollback: The contract has not been upgraded yet!"
+        );
+        return Err(ContractError::NoPreviousVersion);
+    };
+
+    if latest_upgrade.with_migration {
+        log!(
+            env,
+            "Multisig: Rollback: Upgrades that migrated the storage can't be rolled back!"
+        );
+        return Err(ContractError::MigratedUpgradeNotRevertible);
+    }
+
+    match history.len().checked_sub(2).and_then(|i| history.get(i)) {
+        Some(previous_upgrade) => Ok(previous_upgrade.wasm_hash),
+        None => {
+            log!(
+                env,
+                "Multisig: Rollback: There is no previous wasm hash to roll back to!"
+            );
+            Err(ContractError::NoPreviousVersion)
+        }
+    }
+}
+
+fn record_upgrade(env: &Env, wasm_hash: BytesN<32>, proposal_id: u64, with_migration: bool) {
+    let mut history = get_upgrade_history(env);
+    let record = UpgradeRecord {
+        wasm_hash,
+        timestamp: env.ledger().timestamp(),
+        proposal_id,
+        with_migration,
+    };
+    history.push_back(record.clone());
+    save_upgrade_history(env, &history);
+
+    env.events()
+        .publish(("Multisig", "Upgrade contract"), record);
+}
+
+// Moves the proposal past every approval stage whose quorum is already reached,
+// returns true if the current stage has changed
+fn advance_approval_stages(env: &Env, proposal: &mut Proposal) -> bool {
+    let proposal_signatures = get_proposal_signatures(env, proposal.id);
+    let mut advanced = false;
+
+    while let Some(stage) = proposal.stages.get(proposal.current_stage) {
+        let mut signed = 0u32;
+        for signer in stage.signers.iter() {
+            if proposal_signatures.contains_key(signer) {
+                signed += 1;
+            }
+        }
+
+        let required_quorum = Decimal::bps(stage.quorum_bps as i64);
+        if Decimal::from_ratio(signed, stage.signers.len()) < required_quorum {
+            break;
+        }
+
+        env.events().publish(
+            ("Multisig", "Approval stage passed"),
+            (proposal.id, stage.name),
+        );
+        proposal.current_stage += 1;
+        advanced = true;
+    }
+
+    advanced
+}
+
+// Checks that the title and the description of a proposal aren't too long
+fn verify_title_and_description(
+    env: &Env,
+    title: &String,
+    description: &String,
+) -> Result<(), ContractError> {
+    if title.len() > 64 {
+        log!(env, "Multisig: Proposal: Title longer than 64 characters!");
+        return Err(ContractError::TitleTooLong);
+    }
+    if description.len() > 256 {
+        log!(
+            env,
+            "Multisig: Proposal: Description longer than 256 characters!"
+        );
+        return Err(ContractError::DescriptionTooLong);
+    }
+
+    Ok(())
+}
+
+// Fails for addresses on the blocklist, every outgoing payment has to pass this check
+fn verify_not_blocked(env: &Env, recipient: &Address) -> Result<(), ContractError> {
+    if get_blocklist(env).contains_key(recipient.clone()) {
+        log!(env, "Multisig: Blocklist: Recipient is on the blocklist!");
+        return Err(ContractError::RecipientBlocked);
+    }
+
+    Ok(())
+}
+
+// Address the proposal pays or grants funds to once it's executed, if any
+fn get_proposal_payee(env: &Env, proposal: &ProposalType) -> Option<Address> {
+    match proposal {
+        ProposalType::Transaction(t) => Some(t.recipient.clone()),
+        ProposalType::Stream(StreamAction::Create(terms)) => Some(terms.recipient.clone()),
+        ProposalType::Vesting(VestingAction::Create(terms)) => Some(terms.beneficiary.clone()),
+        ProposalType::Approve(allowance) => Some(allowance.spender.clone()),
+        ProposalType::TransferFrom(transfer)
+            if transfer.recipient != env.current_contract_address() =>
+        {
+            Some(transfer.recipient.clone())
+        }
+        ProposalType::Mint(mint) if mint.recipient != env.current_contract_address() => {
+            Some(mint.recipient.clone())
+        }
+        ProposalType::Swap(swap) => Some(swap.counterparty.clone()),
+        _ => None,
+    }
+}
+
+// If an upgrade registry is configured, the wasm hash has to be published by it
+fn verify_wasm_hash_published(env: &Env, wasm_hash: &BytesN<32>) -> Result<(), ContractError> {
+    let Some(registry) = get_upgrade_registry(env) else {
+        return Ok(());
+    };
+
+    match upgrade_registry::Client::new(env, &registry).query_published_wasm(wasm_hash) {
+        Some(audit_note) => {
+            env.events()
+                .publish(("Multisig", "Upgrade audit note"), audit_note);
+            Ok(())
+        }
+        None => {
+            log!(
+                env,
+                "Multisig: Verify wasm hash: Wasm hash is not published by the upgrade registry!"
+            );
+            Err(ContractError::WasmHashNotApproved)
+        }
+    }
+}
+
+// Entrypoints the new code has to expose, they can be called on an uninitialized contract
+const REQUIRED_ENTRYPOINTS: [&str; 3] = [
+    "query_last_proposal_id",
+    "query_all_proposals",
+    "query_storage_version",
+];
+
+// Deploys a probe instance of the new code and calls the entrypoints the multisig needs.
+// Only used by `query_wasm_compatibility`, which is meant to be simulated, so no probe
+// contract is left behind on chain. Deployment fails if the wasm was never uploaded.
+fn verify_wasm_compatible(env: &Env, wasm_hash: &BytesN<32>) -> Result<(), ContractError> {
+    // a probe left behind by a submitted call doesn't block later checks
+    let mut salt = Bytes::from_array(env, &wasm_hash.to_array());
+    salt.extend_from_array(&env.ledger().sequence().to_be_bytes());
+    let salt = env.crypto().sha256(&salt);
+
+    let probe = env
+        .deployer()
+        .with_current_contract(salt)
+        .deploy(wasm_hash.clone());
+
+    for entrypoint in REQUIRED_ENTRYPOINTS {
+        let result = env.try_invoke_contract::<Val, InvokeError>(
+            &probe,
+            &Symbol::new(env, entrypoint),
+            vec![env],
+        );
+        if !matches!(result, Ok(Ok(_))) {
+            log!(
+                env,
+                "Multisig: Verify wasm: New code doesn't expose a required entrypoint!"
+            );
+            return Err(ContractError::IncompatibleWasm);
+        }
+    }
+
+    Ok(())
+}
+
+// The execution condition of the proposal, if any, has to hold at the time of execution
+fn verify_execution_condition(env: &Env, proposal_id: u64) -> Result<(), ContractError> {
+    let is_met = match get_execution_condition(env, proposal_id) {
+        None => true,
+        Some(ExecutionCondition::PriceBelow(bound)) => {
+            price_oracle::Client::new(env, &bound.oracle)
+                .lastprice(&bound.asset)
+                .is_some_and(|price| price < bound.bound)
+        }
+        Some(ExecutionCondition::PriceAbove(bound)) => {
+            price_oracle::Client::new(env, &bound.oracle)
+                .lastprice(&bound.asset)
+                .is_some_and(|price| price > bound.bound)
+        }
+        Some(ExecutionCondition::ViewCall(call)) => {
+            // the entry could have been removed since the condition was set
+            verify_view_call_target(env, &call.contract)?;
+            matches!(
+                env.try_invoke_contract::<bool, InvokeError>(
+                    &call.contract,
+                    &call.function,
+                    call.args
+                ),
+                Ok(Ok(true))
+            )
+        }
+    };
+
+    if !is_met {
+        log!(
+            env,
+            "Multisig: Execute proposal: Execution condition is not met!"
+        );
+        return Err(ContractError::ConditionNotMet);
+    }
+
+    Ok(())
+}
+
+// The multisig is the direct caller of a view call, which authorizes it for anything
+// the callee checks with `require_auth`. Only contracts the members approved in the
+// address book can be called, and never a token the multisig holds.
+fn verify_view_call_target(env: &Env, contract: &Address) -> Result<(), ContractError> {
+    let address_book = get_address_book(env);
+    if !address_book.contains_key((contract.clone(), AddressBookKind::Contract)) {
+        log!(
+            env,
+            "Multisig: Execution condition: Contract is not an approved address book entry!"
+        );
+        return Err(ContractError::AddressNotInAddressBook);
+    }
+    if address_book.contains_key((contract.clone(), AddressBookKind::Token))
+        || get_tracked_tokens(env).contains_key(contract.clone())
+        || get_budgets(env).contains_key(contract.clone())
+    {
+        log!(
+            env,
+            "Multisig: Execution condition: Tokens of the treasury can't be called!"
+        );
+        return Err(ContractError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+// The token rejects allowances that expire before the current ledger
+fn verify_expiration_ledger(env: &Env, expiration_ledger: u32) -> Result<(), ContractError> {
+    if expiration_ledger < env.ledger().sequence() {
+        log!(
+            env,
+            "Multisig: Approve: Expiration ledger is before the current ledger!"
+        );
+        return Err(ContractError::InvalidExpirationLedger);
+    }
+
+    Ok(())
+}
+
+// In strict mode the recipient and the token both have to be approved in the address book
+fn verify_address_book(
+    env: &Env,
+    recipient: &Address,
+    token: &Address,
+) -> Result<(), ContractError> {
+    if !is_address_book_strict(env) {
+        return Ok(());
+    }
+
+    let address_book = get_address_book(env);
+    let is_listed = |address: &Address, kind: AddressBookKind| {
+        address_book.contains_key((address.clone(), kind))
+    };
+
+    if !is_listed(recipient, AddressBookKind::Recipient) {
+        log!(
+            env,
+            "Multisig: Address book: Recipient is not an approved address book entry!"
+        );
+        return Err(ContractError::AddressNotInAddressBook);
+    }
+    if !is_listed(token, AddressBookKind::Token) {
+        log!(
+            env,
+            "Multisig: Address book: Token is not an approved address book entry!"
+        );
+        return Err(ContractError::AddressNotInAddressBook);
+    }
+
+    Ok(())
+}
+
+fn verify_approval_stages(env: &Env, stages: &Vec<ApprovalStage>) -> Result<(), ContractError> {
+    let multisig = get_multisig_members(env);
+
+    for stage in stages.iter() {
+        if stage.signers.is_empty() {
+            log!(
+                env,
+                "Multisig: Create config proposal: Approval stage without any signers!"
+            );
+            return Err(ContractError::InvalidApprovalStage);
+        }
+        if stage
+            .signers
+            .iter()
+            .any(|signer| !multisig.contains_key(signer))
+        {
+            log!(
+                env,
+                "Multisig: Create config proposal: Approval stage signers have to be members!"
+            );
+            return Err(ContractError::UnauthorizedNotAMember);
+        }
+        if stage.quorum_bps <= 100 || stage.quorum_bps > 10_000 {
+            log!(
+                env,
+                "Multisig: Create config proposal: Approval stage quorum has to be above 100 and at most 100%!"
+            );
+            return Err(ContractError::InvalidApprovalStage);
+        }
+    }
+
+    Ok(())
 }
 
 fn verify_members(env: &Env, members: &Vec<Address>) {
@@ -18,4 +18,40 @@ pub enum ContractError {
     InvalidExpirationDate = 11,
     MembersListEmpty = 12,
     ZeroAddressProvided = 13,
+    InvalidDelegation = 14,
+    DelegationNotFound = 15,
+    MandatorySignatureMissing = 16,
+    SignerNotInCurrentStage = 17,
+    InvalidApprovalStage = 18,
+    AddressNotInAddressBook = 19,
+    LabelTooLong = 20,
+    RecipientBlocked = 21,
+    AddressNotBlocked = 22,
+    MigrationNotScheduled = 23,
+    InvalidMigrationVersion = 24,
+    WasmHashNotApproved = 25,
+    NoPreviousVersion = 26,
+    MigratedUpgradeNotRevertible = 27,
+    IncompatibleWasm = 28,
+    InvalidAmount = 29,
+    DepositNotFound = 30,
+    InsufficientBalance = 31,
+    BudgetExceeded = 32,
+    ProposalAlreadySigned = 33,
+    InvalidAmendment = 34,
+    InvalidStream = 35,
+    StreamNotFound = 36,
+    InvalidVesting = 37,
+    VestingNotFound = 38,
+    ClaimNotFound = 39,
+    ClaimExpired = 40,
+    InvalidExpirationLedger = 41,
+    InvalidSwap = 42,
+    SwapNotRefundable = 43,
+    ConditionNotMet = 44,
+    InvalidNotBefore = 45,
+    ProposalNotReady = 46,
+    InvalidDependency = 47,
+    DependencyNotExecuted = 48,
+    ProposalOutOfOrder = 49,
 }
@@ -2,6 +2,7 @@
 
 mod contract;
 mod error;
+mod migration;
 mod storage;
 
 pub mod token_contract {
@@ -12,6 +13,29 @@ pub mod token_contract {
         file = "../../target/wasm32-unknown-unknown/release/soroban_token_contract.wasm"
     );
 }
+
+pub mod upgrade_registry {
+    use soroban_sdk::{contractclient, BytesN, Env, String};
+
+    // Registry of audited multisig releases, implemented by the `MultisigDeployer`
+    #[contractclient(name = "Client")]
+    pub trait UpgradeRegistry {
+        // Returns the audit note of a published wasm hash, `None` if it wasn't published
+        fn query_published_wasm(env: Env, wasm_hash: BytesN<32>) -> Option<String>;
+    }
+}
+
+pub mod price_oracle {
+    use soroban_sdk::{contractclient, Address, Env};
+
+    // Price feed used by the execution conditions of proposals
+    #[contractclient(name = "Client")]
+    pub trait PriceOracle {
+        // Returns the latest price of the asset, `None` if the oracle has no price for it
+        fn lastprice(env: Env, asset: Address) -> Option<i128>;
+    }
+}
+
 // Values used to extend the TTL of storage
 pub const DAY_IN_LEDGERS: u32 = 17280;
 pub const BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
@@ -19,7 +43,17 @@ pub const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 // Values used to track time of proposals lifespan
 pub const ONE_HOUR: u64 = 3_600u64;
+pub const ONE_DAY: u64 = 86_400u64;
 pub const SEVEN_DAYS_EXPIRATION_DATE: u64 = 604_800u64;
+// Funds of streams, vestings and swap escrows stay reserved until they're paid out
+pub const NO_EXPIRATION: u64 = u64::MAX;
+// Proposals the strict sequence cursor steps over in one call, so the reads stay
+// within the ledger limits of a transaction
+pub const MAX_SEQUENCE_STEPS: u32 = 20;
+
+// Version of the storage layout written by this code, bump it together with
+// a new step in `migration.rs` whenever a stored type changes
+pub const STORAGE_VERSION: u32 = 4;
 
 // helper value that represents Soroban's zero address
 pub const SOROBAN_ZERO_ADDRESS: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
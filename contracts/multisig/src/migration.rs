@@ -0,0 +1,81 @@
+use soroban_sdk::{Env, FromVal, IntoVal, Map, Symbol, TryFromVal, Val, Vec};
+
+use crate::{storage::DataKey, BUMP_AMOUNT, LIFETIME_THRESHOLD};
+
+// Applies every migration step between `from_version` and `STORAGE_VERSION` to a
+// single proposal, so the migration can be split over several transactions
+pub fn migrate_proposal(env: &Env, proposal_id: u64, from_version: u32) {
+    let proposal_key = DataKey::Proposal(proposal_id);
+    // proposals are stored as maps of field names, so missing fields can be filled in
+    let proposal = env
+        .storage()
+        .persistent()
+        .get::<_, Map<Symbol, Val>>(&proposal_key);
+    let Some(mut proposal) = proposal else {
+        return;
+    };
+
+    if from_version < 1 {
+        migrate_to_v1(env, &mut proposal);
+    }
+    if from_version < 2 {
+        migrate_to_v2(env, &mut proposal);
+    }
+    if from_version < 4 {
+        migrate_to_v4(env, &mut proposal);
+    }
+
+    env.storage().persistent().set(&proposal_key, &proposal);
+    env.storage()
+        .persistent()
+        .extend_ttl(&proposal_key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+// Version 1:
+// - proposals carry their approval stages and the current stage
+// - signatures map a member to the address that actually signed, instead of `()`,
+//   both layouts are read by `get_proposal_signatures` so they aren't rewritten
+fn migrate_to_v1(env: &Env, proposal: &mut Map<Symbol, Val>) {
+    let stages = Symbol::new(env, "stages");
+    if !proposal.contains_key(stages.clone()) {
+        proposal.set(stages, Vec::<Val>::new(env).into_val(env));
+        proposal.set(Symbol::new(env, "current_stage"), 0u32.into_val(env));
+    }
+}
+
+// Version 2:
+// - transaction amounts are stored as `i128` instead of `u64`
+fn migrate_to_v2(env: &Env, proposal: &mut Map<Symbol, Val>) {
+    let proposal_type = Symbol::new(env, "proposal");
+    let amount = Symbol::new(env, "amount");
+
+    // enum variants are stored as a vector of the variant name and its value
+    let mut variant = Vec::<Val>::from_val(env, &proposal.get_unchecked(proposal_type.clone()));
+    if variant
+        .get(0)
+        .and_then(|name| Symbol::try_from_val(env, &name).ok())
+        != Some(Symbol::new(env, "Transaction"))
+    {
+        return;
+    }
+
+    let mut tx = Map::<Symbol, Val>::from_val(env, &variant.get_unchecked(1));
+    if let Ok(old_amount) = u64::try_from_val(env, &tx.get_unchecked(amount.clone())) {
+        tx.set(amount, (old_amount as i128).into_val(env));
+        variant.set(1, tx.into_val(env));
+        proposal.set(proposal_type, variant.into_val(env));
+    }
+}
+
+// Version 3:
+// - funds reserved by open proposals are kept as running totals per token, `migrate`
+//   seeds them once the proposals are converted
+
+// Version 4:
+// - proposals carry an optional earliest execution timestamp
+fn migrate_to_v4(env: &Env, proposal: &mut Map<Symbol, Val>) {
+    let not_before = Symbol::new(env, "not_before");
+    if !proposal.contains_key(not_before.clone()) {
+        proposal.set(not_before, Option::<u64>::None.into_val(env));
+    }
+}
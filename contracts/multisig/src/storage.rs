@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, map, Address, BytesN, Env, Map, String, Vec};
+use soroban_sdk::{
+    contracttype, map, vec, Address, BytesN, Env, FromVal, Map, String, Symbol, Val, Vec,
+};
 
 use crate::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
 
@@ -13,6 +15,15 @@ pub struct Proposal {
     pub expiration_timestamp: u64,
     pub title: String,
     pub description: String,
+    // Ordered approval stages the proposal has to pass, empty when the multisig
+    // doesn't use an approval workflow
+    pub stages: Vec<ApprovalStage>,
+    // Index of the stage that is currently collecting signatures,
+    // equal to the number of stages once all of them are passed
+    pub current_stage: u32,
+    // Earliest timestamp the proposal can be executed at, signatures can be
+    // collected before it
+    pub not_before: Option<u64>,
 }
 
 #[contracttype]
@@ -22,6 +33,34 @@ pub enum ProposalType {
     Transaction(Transaction),
     // Update the multisig's wasm bytecode with this wasm hash
     UpdateContract(BytesN<32>),
+    // Change the configuration of the multisig
+    UpdateConfig(ConfigUpdate),
+    // Add or remove an address book entry or toggle the strict mode
+    UpdateAddressBook(AddressBookUpdate),
+    // Add or remove an address the multisig must never pay
+    UpdateBlocklist(BlocklistUpdate),
+    // Update the multisig's wasm bytecode and schedule a storage migration
+    UpdateContractWithMigration(ContractMigration),
+    // Restore the wasm hash that was installed before the latest upgrade
+    Rollback(BytesN<32>),
+    // Create, adjust or cancel a payment stream
+    Stream(StreamAction),
+    // Create or revoke a vesting schedule
+    Vesting(VestingAction),
+    // Allow the spender to transfer tokens of the multisig
+    Approve(Allowance),
+    // Set the allowance of the spender to zero
+    RevokeAllowance(AllowanceRevocation),
+    // Transfer tokens of an account that approved the multisig as spender
+    TransferFrom(TransferFrom),
+    // Destroy tokens held by the multisig
+    Burn(Burn),
+    // Mint tokens of a token administered by the multisig
+    Mint(Mint),
+    // Hand over the administration of a token to another address
+    SetTokenAdmin(TokenAdmin),
+    // Exchange tokens with a counterparty who escrowed their side on creation
+    Swap(Swap),
 }
 
 #[contracttype]
@@ -29,14 +68,175 @@ pub enum ProposalType {
 pub enum ProposalStatus {
     Open,
     Closed,
+    // Proposal was vetoed by one of the veto holders or would have paid a blocked address
+    Rejected,
+    // Proposal was withdrawn by its creator or one of its dependencies failed
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigUpdate {
+    // Replace the mandatory signers and veto holders of the multisig
+    SignerRoles(SignerRoles),
+    // Replace the approval workflow used by new proposals, empty vector disables it
+    ApprovalStages(Vec<ApprovalStage>),
+    // Restrict contract updates to wasm hashes published by the given registry
+    UpgradeRegistry(Address),
+    // Allow contract updates to any wasm hash
+    RemoveUpgradeRegistry,
+    // BPS quorum required by rollback proposals, they skip the approval stages
+    RollbackQuorumBps(u32),
+    // Set the spending cap of a token per period, replaces the token's previous budget
+    Budget(Budget),
+    // Remove the spending cap of a token
+    RemoveBudget(Address),
+    // BPS quorum required by proposals marked as budget-exceeding
+    BudgetExceedingQuorumBps(u32),
+    // Keep inflow and outflow totals of a token and report it in the treasury queries
+    TrackToken(Address),
+    // Stop tracking a token, its totals are dropped
+    UntrackToken(Address),
+    // Execute proposals only in the order they were created
+    StrictSequence(bool),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApprovalStage {
+    pub name: String,
+    // Members that approve the proposal in this stage
+    pub signers: Vec<Address>,
+    // BPS of the stage's signers required to pass to the next stage
+    pub quorum_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignerRoles {
+    // Members whose signature is required for any proposal to pass the quorum
+    pub mandatory_signers: Vec<Address>,
+    // Members that can reject any open proposal on their own
+    pub veto_holders: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressBookKind {
+    Recipient,
+    Token,
+    // Contract whose functions execution conditions may call
+    Contract,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressBookEntry {
+    pub address: Address,
+    pub kind: AddressBookKind,
+    pub label: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressBookUpdate {
+    Add(AddressBookEntry),
+    // Remove the entry of the address with the given kind
+    Remove(Address, AddressBookKind),
+    // When enabled, transaction proposals can only pay approved recipients in approved tokens
+    SetStrictMode(bool),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractMigration {
+    pub new_wasm_hash: BytesN<32>,
+    // Storage version the new code has to migrate from
+    pub from_version: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationProgress {
+    // Next proposal to convert
+    pub next_proposal_id: u64,
+    // Last proposal when the migration started, later ones are written in the new layout
+    pub last_proposal_id: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlocklistUpdate {
+    Add(Address),
+    Remove(Address),
 }
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Transaction {
     pub token: Address,
-    pub amount: u64,
+    pub amount: i128,
+    pub recipient: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Allowance {
+    pub token: Address,
+    pub spender: Address,
+    pub amount: i128,
+    // Last ledger sequence the allowance is valid in
+    pub expiration_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AllowanceRevocation {
+    pub token: Address,
+    pub spender: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferFrom {
+    pub token: Address,
+    pub from: Address,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Burn {
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mint {
+    pub token: Address,
     pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenAdmin {
+    pub token: Address,
+    pub new_admin: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Swap {
+    pub counterparty: Address,
+    // Sent by the multisig on execution
+    pub send_token: Address,
+    pub send_amount: i128,
+    // Escrowed by the counterparty when the proposal is created
+    pub receive_token: Address,
+    pub receive_amount: i128,
 }
 
 #[contracttype]
@@ -47,6 +247,224 @@ pub struct MultisigInfo {
     pub members: Vec<Address>,
     pub quorum_bps: u32,
     pub version_proposal: u32,
+    pub upgrade_history: Vec<UpgradeRecord>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeRecord {
+    // Installed wasm hash, the previous one is held by the record before it. The
+    // deployed code is only known if it was passed to `initialize`, otherwise the
+    // first upgrade has no previous wasm hash.
+    pub wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+    // 0 for the code the contract was deployed with
+    pub proposal_id: u64,
+    // Storage was migrated after this upgrade, so it can't be rolled back
+    pub with_migration: bool,
+}
+
+// Running totals of a tracked token
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TokenFlows {
+    // Tokens received through `deposit` or brought in by executed proposals
+    pub total_inflow: i128,
+    // Tokens paid out or burned by executed proposals and claims
+    pub total_outflow: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StreamAction {
+    Create(StreamTerms),
+    Update(StreamUpdate),
+    // Stop the stream, the recipient can still claim what accrued until now
+    Cancel(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StreamTerms {
+    pub recipient: Address,
+    pub token: Address,
+    // Paid for every full period, use a period of one second for a per-second rate
+    pub amount_per_period: i128,
+    pub period: u64,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StreamUpdate {
+    pub stream_id: u64,
+    pub amount_per_period: i128,
+    pub period: u64,
+    pub end_timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stream {
+    pub id: u64,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount_per_period: i128,
+    pub period: u64,
+    // Start of the current terms, moved to the last full period when the stream is updated
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    // Accrued under previous terms and not claimed yet
+    pub carried_over: i128,
+    // Claimed under the current terms
+    pub claimed: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VestingAction {
+    Create(VestingTerms),
+    // Stop the vesting, the unvested amount stays in the treasury
+    Revoke(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VestingTerms {
+    pub beneficiary: Address,
+    pub token: Address,
+    pub amount: i128,
+    // Nothing can be claimed before the cliff, after it the amount unlocks linearly
+    // from the start until the end
+    pub start_timestamp: u64,
+    pub cliff_timestamp: u64,
+    pub end_timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vesting {
+    pub id: u64,
+    pub beneficiary: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub start_timestamp: u64,
+    pub cliff_timestamp: u64,
+    pub end_timestamp: u64,
+    pub claimed: i128,
+    // Vesting stops at this timestamp once revoked
+    pub revoked_timestamp: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Claim {
+    pub proposal_id: u64,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    // After the deadline the amount returns to the treasury
+    pub deadline: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExecutionCondition {
+    // The oracle price of the asset has to be below the bound
+    PriceBelow(PriceBound),
+    // The oracle price of the asset has to be above the bound
+    PriceAbove(PriceBound),
+    // The view function has to return true, the contract has to be an address book
+    // entry of kind `Contract`
+    ViewCall(ViewCall),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceBound {
+    pub oracle: Address,
+    pub asset: Address,
+    pub bound: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ViewCall {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Budget {
+    pub token: Address,
+    // Amount that can be spent per period
+    pub amount: i128,
+    pub period: BudgetPeriod,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BudgetPeriod {
+    // Fixed length in seconds, periods start at multiples of it
+    Seconds(u64),
+    // UTC calendar month, periods start on the first day of each month
+    CalendarMonth,
+}
+
+// Spending of a budgeted token in its current period
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BudgetUsage {
+    pub period_start: u64,
+    pub spent: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BudgetStatus {
+    pub budget: Budget,
+    pub period_start: u64,
+    pub spent: i128,
+    pub remaining: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Deposit {
+    pub id: u64,
+    pub from: Address,
+    pub token: Address,
+    pub amount: i128,
+    // Reference given by the depositor, e.g. an invoice number
+    pub memo: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreasuryBalance {
+    pub token: Address,
+    pub balance: i128,
+    // Held back for open proposals and for payments the multisig still owes
+    pub reserved: i128,
+    // Balance that isn't reserved, can be negative if the treasury is overdrawn
+    pub available: i128,
+    pub total_inflow: i128,
+    pub total_outflow: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Delegation {
+    // Member that hands over the signing right
+    pub member: Address,
+    // Address allowed to sign on behalf of the member
+    pub delegate: Address,
+    // Timestamp after which the delegation is no longer valid
+    pub expiration_timestamp: u64,
 }
 
 #[contracttype]
@@ -64,10 +482,81 @@ pub enum DataKey {
     LastProposalId,
     // Details of the tranasction proposal
     Proposal(u64),
-    // Record of signatures to each transaction proposal
+    // Record of signatures to each transaction proposal, maps a member to the address
+    // that actually signed (the member itself or its delegate), or to `()` if it was
+    // signed before delegations existed
     // TODO: Add method to clean up memory when the transaction is executed
     ProposalSignatures(u64),
     Version,
+    // Active signing delegations, keyed by the delegating member
+    Delegations,
+    // Mandatory signers and veto holders
+    SignerRoles,
+    // Approval workflow copied into every new proposal
+    ApprovalStages,
+    // Approved recipients and tokens, keyed by their address and kind, so one
+    // address can be approved as both
+    AddressBook,
+    // If set, transactions are limited to the entries of the address book
+    AddressBookStrictMode,
+    // Sanctioned or compromised addresses that can't receive any funds
+    Blocklist,
+    // Version of the storage layout, missing for contracts deployed before it was tracked
+    StorageVersion,
+    // Proposals left to convert by a migration that is split over several calls
+    MigrationProgress,
+    // Registry of audited wasm hashes that contract updates are limited to
+    UpgradeRegistry,
+    // Every executed upgrade and rollback, oldest first
+    UpgradeHistory,
+    // Quorum of rollback proposals, the regular quorum is used when it isn't set
+    RollbackQuorumBps,
+    // Tokens tracked by config proposals, with their inflow and outflow totals
+    TrackedTokens,
+    // Amounts of a token reserved by proposals, claims, streams, vestings and swap
+    // escrows, keyed by the timestamp they stay reserved until
+    Reservations(Address),
+    // Unique identifier of each deposit
+    LastDepositId,
+    // Depositor and memo of a deposit
+    Deposit(u64),
+    // Spending caps, keyed by the token
+    Budgets,
+    // Spending of each budgeted token in its current period
+    BudgetUsage,
+    // Quorum of budget-exceeding proposals, unanimity is required when it isn't set
+    BudgetExceedingQuorumBps,
+    // Set if the proposal may go over the budget with the higher quorum
+    BudgetExceeding(u64),
+    // Unique identifier of each payment stream
+    LastStreamId,
+    // Schedule and claimed amount of a payment stream
+    Stream(u64),
+    // Unique identifier of each vesting schedule
+    LastVestingId,
+    // Schedule and claimed amount of a vesting
+    Vesting(u64),
+    // Claim window of a claimable transaction proposal
+    ClaimWindow(u64),
+    // Payment of an executed claimable transaction proposal, removed once claimed
+    Claim(u64),
+    // Spenders allowed by executed approve proposals to transfer the multisig's tokens
+    Spenders(Address),
+    // Set while the multisig holds the counterparty's side of a swap proposal
+    SwapEscrow(u64),
+    // Condition checked when the proposal is executed
+    ExecutionCondition(u64),
+    // Set if proposals have to be executed in the order they were created
+    StrictSequence,
+    // In strict sequence mode, the lowest proposal ID that may still be open, every
+    // earlier proposal is settled
+    UnsettledProposalCursor,
+    // Proposals that have to be executed before the proposal
+    Dependencies(u64),
+    // Proposals that depend on the proposal, cancelled together with it
+    Dependents(u64),
+    // Number of times the creator amended the proposal
+    Amendments(u64),
 }
 
 pub fn set_initialized(env: &Env) {
@@ -286,9 +775,10 @@ pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
 // -------------
 
 // When user signes the given proposal, save an information about it
-pub fn save_proposal_signature(e: &Env, proposal_id: u64, signer: Address) {
-    let mut proposal_signatures: Map<Address, ()> = get_proposal_signatures(e, proposal_id);
-    proposal_signatures.set(signer, ());
+// `member` is the one the signature counts for, `signer` is who actually signed it
+pub fn save_proposal_signature(e: &Env, proposal_id: u64, member: Address, signer: Address) {
+    let mut proposal_signatures: Map<Address, Address> = get_proposal_signatures(e, proposal_id);
+    proposal_signatures.set(member, signer);
 
     e.storage().persistent().set(
         &DataKey::ProposalSignatures(proposal_id),
@@ -301,13 +791,25 @@ pub fn save_proposal_signature(e: &Env, proposal_id: u64, signer: Address) {
     );
 }
 
-pub fn get_proposal_signatures(env: &Env, proposal_id: u64) -> Map<Address, ()> {
-    let proposal_signatures = env
+pub fn get_proposal_signatures(env: &Env, proposal_id: u64) -> Map<Address, Address> {
+    let stored_signatures: Map<Address, Val> = env
         .storage()
         .persistent()
         .get(&DataKey::ProposalSignatures(proposal_id))
         .unwrap_or(map![&env]);
 
+    // contracts deployed before delegations stored `()` for every signature,
+    // those were all made by the member itself
+    let mut proposal_signatures: Map<Address, Address> = map![&env];
+    for (member, signer) in stored_signatures {
+        let signer = if signer.is_void() {
+            member.clone()
+        } else {
+            Address::from_val(env, &signer)
+        };
+        proposal_signatures.set(member, signer);
+    }
+
     env.storage()
         .persistent()
         .has(&DataKey::ProposalSignatures(proposal_id))
@@ -322,30 +824,1012 @@ pub fn get_proposal_signatures(env: &Env, proposal_id: u64) -> Map<Address, ()>
     proposal_signatures
 }
 
-pub fn save_version(env: &Env, version: &u32) {
-    env.storage().persistent().set(&DataKey::Version, version);
+// -------------
+
+pub fn get_delegations(env: &Env) -> Map<Address, Delegation> {
+    let delegations = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Delegations)
+        .unwrap_or(map![env]);
+
     env.storage()
         .persistent()
-        .extend_ttl(&DataKey::Version, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+        .has(&DataKey::Delegations)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::Delegations,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    delegations
 }
 
-pub fn get_version(env: &Env) -> u32 {
-    let version = env
+pub fn save_delegations(env: &Env, delegations: &Map<Address, Delegation>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Delegations, delegations);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::Delegations, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+// -------------
+
+pub fn get_signer_roles(env: &Env) -> SignerRoles {
+    let signer_roles = env
         .storage()
         .persistent()
-        .get(&DataKey::Version)
-        .unwrap_or_default();
+        .get(&DataKey::SignerRoles)
+        .unwrap_or(SignerRoles {
+            mandatory_signers: vec![env],
+            veto_holders: vec![env],
+        });
 
-    env.storage().persistent().has(&DataKey::Version).then(|| {
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Version, LIFETIME_THRESHOLD, BUMP_AMOUNT)
-    });
+    env.storage()
+        .persistent()
+        .has(&DataKey::SignerRoles)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::SignerRoles,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
 
-    version
+    signer_roles
 }
 
-pub fn increase_version(env: &Env) {
-    let version = get_version(env) + 1;
-    save_version(env, &version);
+pub fn save_signer_roles(env: &Env, signer_roles: &SignerRoles) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SignerRoles, signer_roles);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::SignerRoles, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+// -------------
+
+pub fn get_approval_stages(env: &Env) -> Vec<ApprovalStage> {
+    let approval_stages = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ApprovalStages)
+        .unwrap_or(vec![env]);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::ApprovalStages)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::ApprovalStages,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    approval_stages
+}
+
+pub fn save_approval_stages(env: &Env, approval_stages: &Vec<ApprovalStage>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ApprovalStages, approval_stages);
+    env.storage().persistent().extend_ttl(
+        &DataKey::ApprovalStages,
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+// -------------
+
+pub fn get_address_book(env: &Env) -> Map<(Address, AddressBookKind), AddressBookEntry> {
+    let address_book = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AddressBook)
+        .unwrap_or(map![env]);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::AddressBook)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::AddressBook,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    address_book
+}
+
+pub fn save_address_book(
+    env: &Env,
+    address_book: &Map<(Address, AddressBookKind), AddressBookEntry>,
+) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AddressBook, address_book);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::AddressBook, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn is_address_book_strict(env: &Env) -> bool {
+    let strict_mode = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AddressBookStrictMode)
+        .unwrap_or_default();
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::AddressBookStrictMode)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::AddressBookStrictMode,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    strict_mode
+}
+
+pub fn save_address_book_strict_mode(env: &Env, strict_mode: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AddressBookStrictMode, &strict_mode);
+    env.storage().persistent().extend_ttl(
+        &DataKey::AddressBookStrictMode,
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+// -------------
+
+pub fn get_blocklist(env: &Env) -> Map<Address, ()> {
+    let blocklist = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Blocklist)
+        .unwrap_or(map![env]);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::Blocklist)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::Blocklist,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    blocklist
+}
+
+pub fn save_blocklist(env: &Env, blocklist: &Map<Address, ()>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Blocklist, blocklist);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::Blocklist, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+// -------------
+
+pub fn save_version(env: &Env, version: &u32) {
+    env.storage().persistent().set(&DataKey::Version, version);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::Version, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn get_version(env: &Env) -> u32 {
+    let version = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Version)
+        .unwrap_or_default();
+
+    env.storage().persistent().has(&DataKey::Version).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::Version, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    version
+}
+
+pub fn increase_version(env: &Env) {
+    let version = get_version(env) + 1;
+    save_version(env, &version);
+}
+
+pub fn save_storage_version(env: &Env, version: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::StorageVersion, &version);
+    env.storage().persistent().extend_ttl(
+        &DataKey::StorageVersion,
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+pub fn get_storage_version(env: &Env) -> u32 {
+    let version = env
+        .storage()
+        .persistent()
+        .get(&DataKey::StorageVersion)
+        .unwrap_or_default();
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::StorageVersion)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::StorageVersion,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    version
+}
+
+pub fn get_migration_progress(env: &Env) -> Option<MigrationProgress> {
+    let progress = env.storage().persistent().get(&DataKey::MigrationProgress);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::MigrationProgress)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::MigrationProgress,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    progress
+}
+
+pub fn save_migration_progress(env: &Env, progress: &Option<MigrationProgress>) {
+    match progress {
+        Some(progress) => {
+            env.storage()
+                .persistent()
+                .set(&DataKey::MigrationProgress, progress);
+            env.storage().persistent().extend_ttl(
+                &DataKey::MigrationProgress,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            );
+        }
+        None => env
+            .storage()
+            .persistent()
+            .remove(&DataKey::MigrationProgress),
+    }
+}
+
+// -------------
+
+pub fn get_upgrade_registry(env: &Env) -> Option<Address> {
+    let registry = env.storage().persistent().get(&DataKey::UpgradeRegistry);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::UpgradeRegistry)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::UpgradeRegistry,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    registry
+}
+
+pub fn save_upgrade_registry(env: &Env, registry: &Option<Address>) {
+    match registry {
+        Some(registry) => {
+            env.storage()
+                .persistent()
+                .set(&DataKey::UpgradeRegistry, registry);
+            env.storage().persistent().extend_ttl(
+                &DataKey::UpgradeRegistry,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            );
+        }
+        None => env.storage().persistent().remove(&DataKey::UpgradeRegistry),
+    }
+}
+
+// -------------
+
+pub fn get_upgrade_history(env: &Env) -> Vec<UpgradeRecord> {
+    let history = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UpgradeHistory)
+        .unwrap_or(vec![env]);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::UpgradeHistory)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::UpgradeHistory,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    history
+}
+
+pub fn save_upgrade_history(env: &Env, history: &Vec<UpgradeRecord>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::UpgradeHistory, history);
+    env.storage().persistent().extend_ttl(
+        &DataKey::UpgradeHistory,
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+pub fn get_rollback_quorum_bps(env: &Env) -> Option<u32> {
+    let quorum_bps = env.storage().persistent().get(&DataKey::RollbackQuorumBps);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::RollbackQuorumBps)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::RollbackQuorumBps,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    quorum_bps
+}
+
+pub fn save_rollback_quorum_bps(env: &Env, quorum_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RollbackQuorumBps, &quorum_bps);
+    env.storage().persistent().extend_ttl(
+        &DataKey::RollbackQuorumBps,
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+// -------------
+
+pub fn get_tracked_tokens(env: &Env) -> Map<Address, TokenFlows> {
+    let tracked_tokens = env
+        .storage()
+        .persistent()
+        .get(&DataKey::TrackedTokens)
+        .unwrap_or(map![env]);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::TrackedTokens)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::TrackedTokens,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    tracked_tokens
+}
+
+pub fn save_tracked_tokens(env: &Env, tracked_tokens: &Map<Address, TokenFlows>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TrackedTokens, tracked_tokens);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::TrackedTokens, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+// -------------
+
+pub fn increment_last_deposit_id(env: &Env) -> u64 {
+    let id = get_last_deposit_id(env) + 1u64;
+    env.storage().persistent().set(&DataKey::LastDepositId, &id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::LastDepositId, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+
+    id
+}
+
+pub fn get_last_deposit_id(env: &Env) -> u64 {
+    let last_id = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LastDepositId)
+        .unwrap_or_default();
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::LastDepositId)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::LastDepositId,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    last_id
+}
+
+pub fn save_deposit(env: &Env, deposit: &Deposit) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Deposit(deposit.id), deposit);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Deposit(deposit.id),
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+pub fn get_deposit(env: &Env, deposit_id: u64) -> Option<Deposit> {
+    let deposit = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Deposit(deposit_id));
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::Deposit(deposit_id))
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::Deposit(deposit_id),
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    deposit
+}
+
+// -------------
+
+pub fn get_budgets(env: &Env) -> Map<Address, Budget> {
+    let budgets = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Budgets)
+        .unwrap_or(map![env]);
+
+    env.storage().persistent().has(&DataKey::Budgets).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::Budgets, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    budgets
+}
+
+pub fn save_budgets(env: &Env, budgets: &Map<Address, Budget>) {
+    env.storage().persistent().set(&DataKey::Budgets, budgets);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::Budgets, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn get_budget_usage(env: &Env) -> Map<Address, BudgetUsage> {
+    let usage = env
+        .storage()
+        .persistent()
+        .get(&DataKey::BudgetUsage)
+        .unwrap_or(map![env]);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::BudgetUsage)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::BudgetUsage,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    usage
+}
+
+pub fn save_budget_usage(env: &Env, usage: &Map<Address, BudgetUsage>) {
+    env.storage().persistent().set(&DataKey::BudgetUsage, usage);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::BudgetUsage, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn get_budget_exceeding_quorum_bps(env: &Env) -> u32 {
+    let quorum_bps = env
+        .storage()
+        .persistent()
+        .get(&DataKey::BudgetExceedingQuorumBps)
+        .unwrap_or(10_000);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::BudgetExceedingQuorumBps)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::BudgetExceedingQuorumBps,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    quorum_bps
+}
+
+pub fn save_budget_exceeding_quorum_bps(env: &Env, quorum_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::BudgetExceedingQuorumBps, &quorum_bps);
+    env.storage().persistent().extend_ttl(
+        &DataKey::BudgetExceedingQuorumBps,
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+pub fn is_budget_exceeding(env: &Env, proposal_id: u64) -> bool {
+    let key = DataKey::BudgetExceeding(proposal_id);
+    let budget_exceeding = env.storage().persistent().has(&key);
+    budget_exceeding.then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    budget_exceeding
+}
+
+pub fn save_budget_exceeding(env: &Env, proposal_id: u64, budget_exceeding: bool) {
+    let key = DataKey::BudgetExceeding(proposal_id);
+    if budget_exceeding {
+        env.storage().persistent().set(&key, &());
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_claim_window(env: &Env, proposal_id: u64) -> Option<u64> {
+    let key = DataKey::ClaimWindow(proposal_id);
+    let claim_window = env.storage().persistent().get(&key);
+    env.storage().persistent().has(&key).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    claim_window
+}
+
+pub fn save_claim_window(env: &Env, proposal_id: u64, claim_window: Option<u64>) {
+    let key = DataKey::ClaimWindow(proposal_id);
+    match claim_window {
+        Some(claim_window) => {
+            env.storage().persistent().set(&key, &claim_window);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_claim(env: &Env, proposal_id: u64) -> Option<Claim> {
+    let key = DataKey::Claim(proposal_id);
+    let claim = env.storage().persistent().get(&key);
+    env.storage().persistent().has(&key).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    claim
+}
+
+pub fn save_claim(env: &Env, claim: &Claim) {
+    let key = DataKey::Claim(claim.proposal_id);
+    env.storage().persistent().set(&key, claim);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn remove_claim(env: &Env, proposal_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Claim(proposal_id));
+}
+
+pub fn is_swap_escrowed(env: &Env, proposal_id: u64) -> bool {
+    let key = DataKey::SwapEscrow(proposal_id);
+    let escrowed = env.storage().persistent().has(&key);
+    escrowed.then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    escrowed
+}
+
+pub fn save_swap_escrowed(env: &Env, proposal_id: u64, escrowed: bool) {
+    let key = DataKey::SwapEscrow(proposal_id);
+    if escrowed {
+        env.storage().persistent().set(&key, &());
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_execution_condition(env: &Env, proposal_id: u64) -> Option<ExecutionCondition> {
+    let key = DataKey::ExecutionCondition(proposal_id);
+    let condition = env.storage().persistent().get(&key);
+    env.storage().persistent().has(&key).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    condition
+}
+
+pub fn save_execution_condition(
+    env: &Env,
+    proposal_id: u64,
+    condition: &Option<ExecutionCondition>,
+) {
+    let key = DataKey::ExecutionCondition(proposal_id);
+    match condition {
+        Some(condition) => {
+            env.storage().persistent().set(&key, condition);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn is_strict_sequence(env: &Env) -> bool {
+    let strict_sequence = env
+        .storage()
+        .persistent()
+        .get(&DataKey::StrictSequence)
+        .unwrap_or_default();
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::StrictSequence)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::StrictSequence,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    strict_sequence
+}
+
+pub fn save_strict_sequence(env: &Env, strict_sequence: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::StrictSequence, &strict_sequence);
+    env.storage().persistent().extend_ttl(
+        &DataKey::StrictSequence,
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+pub fn get_unsettled_proposal_cursor(env: &Env) -> u64 {
+    let cursor = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UnsettledProposalCursor)
+        .unwrap_or(1);
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::UnsettledProposalCursor)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::UnsettledProposalCursor,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    cursor
+}
+
+pub fn save_unsettled_proposal_cursor(env: &Env, cursor: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::UnsettledProposalCursor, &cursor);
+    env.storage().persistent().extend_ttl(
+        &DataKey::UnsettledProposalCursor,
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_unsettled_proposal_cursor(env: &Env) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::UnsettledProposalCursor);
+}
+
+pub fn get_dependencies(env: &Env, proposal_id: u64) -> Vec<u64> {
+    let key = DataKey::Dependencies(proposal_id);
+    let dependencies = env.storage().persistent().get(&key).unwrap_or(vec![env]);
+    env.storage().persistent().has(&key).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    dependencies
+}
+
+pub fn save_dependencies(env: &Env, proposal_id: u64, dependencies: &Vec<u64>) {
+    let key = DataKey::Dependencies(proposal_id);
+    env.storage().persistent().set(&key, dependencies);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn get_dependents(env: &Env, proposal_id: u64) -> Vec<u64> {
+    let key = DataKey::Dependents(proposal_id);
+    let dependents = env.storage().persistent().get(&key).unwrap_or(vec![env]);
+    env.storage().persistent().has(&key).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    dependents
+}
+
+pub fn save_dependents(env: &Env, proposal_id: u64, dependents: &Vec<u64>) {
+    let key = DataKey::Dependents(proposal_id);
+    env.storage().persistent().set(&key, dependents);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn get_amendments(env: &Env, proposal_id: u64) -> u32 {
+    let key = DataKey::Amendments(proposal_id);
+    let amendments = env.storage().persistent().get(&key).unwrap_or_default();
+    env.storage().persistent().has(&key).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    amendments
+}
+
+pub fn increment_amendments(env: &Env, proposal_id: u64) -> u32 {
+    let key = DataKey::Amendments(proposal_id);
+    let amendments = get_amendments(env, proposal_id) + 1;
+    env.storage().persistent().set(&key, &amendments);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+
+    amendments
+}
+
+// -------------
+
+pub fn increment_last_stream_id(env: &Env) -> u64 {
+    let id = get_last_stream_id(env) + 1u64;
+    env.storage().persistent().set(&DataKey::LastStreamId, &id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::LastStreamId, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+
+    id
+}
+
+pub fn get_last_stream_id(env: &Env) -> u64 {
+    let last_id = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LastStreamId)
+        .unwrap_or_default();
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::LastStreamId)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::LastStreamId,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    last_id
+}
+
+pub fn save_stream(env: &Env, stream: &Stream) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Stream(stream.id), stream);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Stream(stream.id),
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+pub fn get_stream(env: &Env, stream_id: u64) -> Option<Stream> {
+    let stream = env.storage().persistent().get(&DataKey::Stream(stream_id));
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::Stream(stream_id))
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::Stream(stream_id),
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    stream
+}
+
+// -------------
+
+pub fn increment_last_vesting_id(env: &Env) -> u64 {
+    let id = get_last_vesting_id(env) + 1u64;
+    env.storage().persistent().set(&DataKey::LastVestingId, &id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::LastVestingId, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+
+    id
+}
+
+pub fn get_last_vesting_id(env: &Env) -> u64 {
+    let last_id = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LastVestingId)
+        .unwrap_or_default();
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::LastVestingId)
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::LastVestingId,
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    last_id
+}
+
+pub fn save_vesting(env: &Env, vesting: &Vesting) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Vesting(vesting.id), vesting);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Vesting(vesting.id),
+        LIFETIME_THRESHOLD,
+        BUMP_AMOUNT,
+    );
+}
+
+pub fn get_vesting(env: &Env, vesting_id: u64) -> Option<Vesting> {
+    let vesting = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Vesting(vesting_id));
+
+    env.storage()
+        .persistent()
+        .has(&DataKey::Vesting(vesting_id))
+        .then(|| {
+            env.storage().persistent().extend_ttl(
+                &DataKey::Vesting(vesting_id),
+                LIFETIME_THRESHOLD,
+                BUMP_AMOUNT,
+            )
+        });
+
+    vesting
+}
+
+pub fn get_reservations(env: &Env, token: &Address) -> Map<u64, i128> {
+    let key = DataKey::Reservations(token.clone());
+    let reservations = env.storage().persistent().get(&key).unwrap_or(map![env]);
+    env.storage().persistent().has(&key).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    reservations
+}
+
+pub fn save_reservations(env: &Env, token: &Address, reservations: &Map<u64, i128>) {
+    let key = DataKey::Reservations(token.clone());
+    if reservations.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, reservations);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+pub fn get_spenders(env: &Env, token: &Address) -> Vec<Address> {
+    let key = DataKey::Spenders(token.clone());
+    let spenders = env.storage().persistent().get(&key).unwrap_or(vec![env]);
+    env.storage().persistent().has(&key).then(|| {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT)
+    });
+
+    spenders
+}
+
+pub fn save_spenders(env: &Env, token: &Address, spenders: &Vec<Address>) {
+    let key = DataKey::Spenders(token.clone());
+    if spenders.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, spenders);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
 }
@@ -1,5 +1,22 @@
+mod address_book;
+mod allowance;
+mod approval_stages;
+mod blocklist;
+mod budget;
+mod claim;
+mod delegation;
+mod dependencies;
+mod execution_condition;
 mod multisig;
+mod rollback;
 mod setup;
+mod signer_roles;
+mod stream;
+mod swap;
+mod token_admin;
 mod transaction_proposal;
+mod treasury;
 
 mod update_proposal;
+mod upgrade_registry;
+mod vesting;
@@ -0,0 +1,321 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+use super::setup::{
+    deploy_token_contract, initialize_multisig_contract, sign_and_execute_proposal,
+};
+use crate::{
+    contract::MultisigClient,
+    error::ContractError,
+    storage::{AddressBookEntry, AddressBookKind, AddressBookUpdate},
+};
+
+fn update_address_book(
+    env: &Env,
+    multisig: &MultisigClient,
+    member: &Address,
+    update: AddressBookUpdate,
+) {
+    multisig.create_address_book_proposal(
+        member,
+        &String::from_str(env, "Address book"),
+        &String::from_str(env, "Update the address book"),
+        &update,
+        &None,
+    );
+    sign_and_execute_proposal(
+        multisig,
+        &vec![env, member.clone()],
+        multisig.query_last_proposal_id(),
+    );
+}
+
+#[test]
+fn strict_mode_limits_transactions_to_address_book() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+    let other_token = deploy_token_contract(&env, &member1);
+    let vendor = Address::generate(&env);
+    let phishing_vendor = Address::generate(&env);
+
+    let vendor_entry = AddressBookEntry {
+        address: vendor.clone(),
+        kind: AddressBookKind::Recipient,
+        label: String::from_str(&env, "Office supplies vendor"),
+    };
+    let token_entry = AddressBookEntry {
+        address: token.address.clone(),
+        kind: AddressBookKind::Token,
+        label: String::from_str(&env, "USDC"),
+    };
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::Add(vendor_entry.clone()),
+    );
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::Add(token_entry.clone()),
+    );
+    assert!(!multisig.query_address_book_strict_mode());
+
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::SetStrictMode(true),
+    );
+    assert!(multisig.query_address_book_strict_mode());
+
+    let address_book = multisig.query_address_book();
+    assert_eq!(address_book.len(), 2);
+    assert!(address_book.contains(vendor_entry));
+    assert!(address_book.contains(token_entry));
+
+    assert_eq!(
+        multisig.try_create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &phishing_vendor,
+            &10_000,
+            &token.address,
+            &None,
+        ),
+        Err(Ok(ContractError::AddressNotInAddressBook))
+    );
+    assert_eq!(
+        multisig.try_create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &vendor,
+            &10_000,
+            &other_token.address,
+            &None,
+        ),
+        Err(Ok(ContractError::AddressNotInAddressBook))
+    );
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &vendor,
+        &10_000,
+        &token.address,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(token.balance(&vendor), 10_000i128);
+}
+
+#[test]
+fn removed_entry_is_no_longer_approved() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        vec![&env, member1.clone()],
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    let vendor = Address::generate(&env);
+
+    assert_eq!(
+        multisig.try_create_address_book_proposal(
+            &member1,
+            &String::from_str(&env, "Address book"),
+            &String::from_str(&env, "Remove vendor"),
+            &AddressBookUpdate::Remove(vendor.clone(), AddressBookKind::Recipient),
+            &None,
+        ),
+        Err(Ok(ContractError::AddressNotInAddressBook))
+    );
+
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::Add(AddressBookEntry {
+            address: vendor.clone(),
+            kind: AddressBookKind::Recipient,
+            label: String::from_str(&env, "Vendor"),
+        }),
+    );
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::Add(AddressBookEntry {
+            address: token.address.clone(),
+            kind: AddressBookKind::Token,
+            label: String::from_str(&env, "Token"),
+        }),
+    );
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::SetStrictMode(true),
+    );
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::Remove(vendor.clone(), AddressBookKind::Recipient),
+    );
+    assert_eq!(multisig.query_address_book().len(), 1);
+
+    assert_eq!(
+        multisig.try_create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &vendor,
+            &10_000,
+            &token.address,
+            &None,
+        ),
+        Err(Ok(ContractError::AddressNotInAddressBook))
+    );
+}
+
+#[test]
+fn address_book_label_too_long() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        vec![&env, member1.clone()],
+        None,
+    );
+
+    assert_eq!(
+        multisig.try_create_address_book_proposal(
+            &member1,
+            &String::from_str(&env, "Address book"),
+            &String::from_str(&env, "Add vendor"),
+            &AddressBookUpdate::Add(AddressBookEntry {
+                address: Address::generate(&env),
+                kind: AddressBookKind::Recipient,
+                label: String::from_bytes(&env, &[0u8; 65]),
+            }),
+            &None,
+        ),
+        Err(Ok(ContractError::LabelTooLong))
+    );
+}
+
+#[test]
+fn address_can_be_approved_as_token_and_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+    // e.g. a token contract that is paid in its own token
+    let token_entry = AddressBookEntry {
+        address: token.address.clone(),
+        kind: AddressBookKind::Token,
+        label: String::from_str(&env, "Token"),
+    };
+    let recipient_entry = AddressBookEntry {
+        address: token.address.clone(),
+        kind: AddressBookKind::Recipient,
+        label: String::from_str(&env, "Token issuer"),
+    };
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::Add(token_entry.clone()),
+    );
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::Add(recipient_entry.clone()),
+    );
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::SetStrictMode(true),
+    );
+    assert_eq!(
+        multisig.query_address_book(),
+        vec![&env, recipient_entry, token_entry.clone()]
+    );
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &token.address,
+        &4_000,
+        &token.address,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(token.balance(&token.address), 4_000i128);
+
+    // removing one kind keeps the other
+    update_address_book(
+        &env,
+        &multisig,
+        &member1,
+        AddressBookUpdate::Remove(token.address.clone(), AddressBookKind::Recipient),
+    );
+    assert_eq!(multisig.query_address_book(), vec![&env, token_entry]);
+    assert_eq!(
+        multisig.try_create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#02"),
+            &String::from_str(&env, "TxTestDescription"),
+            &token.address,
+            &4_000,
+            &token.address,
+            &None,
+        ),
+        Err(Ok(ContractError::AddressNotInAddressBook))
+    );
+}
@@ -0,0 +1,214 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+use super::setup::{
+    deploy_token_contract, initialize_multisig_contract, sign_and_execute_proposal,
+    DAY_AS_TIMESTAMP,
+};
+use crate::{
+    error::ContractError,
+    storage::{Budget, BudgetPeriod, ConfigUpdate},
+};
+
+#[test]
+fn approve_and_revoke_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let router = Address::generate(&env);
+    multisig.create_approve_proposal(
+        &member1,
+        &String::from_str(&env, "Approve"),
+        &String::from_str(&env, "Allowance for the DEX router"),
+        &token.address,
+        &router,
+        &4_000,
+        &1_000,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(token.allowance(&multisig.address, &router), 4_000);
+    let reserved = || {
+        multisig
+            .query_treasury(&vec![&env, token.address.clone()])
+            .get(0)
+            .unwrap()
+            .reserved
+    };
+    assert_eq!(reserved(), 4_000);
+
+    // what is left of the allowance stays reserved
+    let recipient = Address::generate(&env);
+    token.transfer_from(&router, &multisig.address, &recipient, &1_500);
+    assert_eq!(token.balance(&recipient), 1_500);
+    assert_eq!(token.allowance(&multisig.address, &router), 2_500);
+    assert_eq!(reserved(), 2_500);
+    assert_eq!(
+        multisig.try_create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &recipient,
+            &6_001,
+            &token.address,
+            &None,
+        ),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+
+    multisig.create_revoke_allowance_proposal(
+        &member1,
+        &String::from_str(&env, "Revoke"),
+        &String::from_str(&env, "The router is deprecated"),
+        &token.address,
+        &router,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(token.allowance(&multisig.address, &router), 0);
+    assert_eq!(reserved(), 0);
+}
+
+#[test]
+fn invalid_approve_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+    let router = Address::generate(&env);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    let approve = |amount: i128, expiration_ledger: u32| {
+        multisig.try_create_approve_proposal(
+            &member1,
+            &String::from_str(&env, "Approve"),
+            &String::from_str(&env, "Allowance for the DEX router"),
+            &token.address,
+            &router,
+            &amount,
+            &expiration_ledger,
+            &None,
+        )
+    };
+    assert_eq!(approve(0, 1_000), Err(Ok(ContractError::InvalidAmount)));
+    assert_eq!(
+        approve(4_000, 99),
+        Err(Ok(ContractError::InvalidExpirationLedger))
+    );
+    assert_eq!(
+        approve(10_001, 1_000),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+
+    // the expiration ledger is checked again on execution
+    approve(4_000, 200).unwrap().unwrap();
+    let proposal_id = multisig.query_last_proposal_id();
+    multisig.sign_proposal(&member1, &proposal_id);
+    env.ledger().with_mut(|li| li.sequence_number = 201);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::InvalidExpirationLedger))
+    );
+}
+
+#[test]
+fn approved_amount_is_reserved_and_charged_to_the_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    multisig.create_config_proposal(
+        &member1,
+        &String::from_str(&env, "Budget"),
+        &String::from_str(&env, "Monthly spending envelope"),
+        &ConfigUpdate::Budget(Budget {
+            token: token.address.clone(),
+            amount: 5_000,
+            period: BudgetPeriod::Seconds(DAY_AS_TIMESTAMP * 30),
+        }),
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+
+    let router = Address::generate(&env);
+    let approve = |amount: i128| {
+        multisig.try_create_approve_proposal(
+            &member1,
+            &String::from_str(&env, "Approve"),
+            &String::from_str(&env, "Allowance for the DEX router"),
+            &token.address,
+            &router,
+            &amount,
+            &1_000,
+            &None,
+        )
+    };
+
+    // open approve proposals reserve their amount
+    approve(4_000).unwrap().unwrap();
+    let first_id = multisig.query_last_proposal_id();
+    approve(6_000).unwrap().unwrap();
+    let second_id = multisig.query_last_proposal_id();
+    assert_eq!(approve(1), Err(Ok(ContractError::InsufficientBalance)));
+
+    // the whole allowance is charged to the budget on execution
+    sign_and_execute_proposal(&multisig, &members, first_id);
+    assert_eq!(multisig.query_budgets().get(0).unwrap().spent, 4_000);
+    multisig.sign_proposal(&member1, &second_id);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &second_id),
+        Err(Ok(ContractError::BudgetExceeded))
+    );
+    assert_eq!(token.allowance(&multisig.address, &router), 4_000);
+
+    // an expired allowance isn't reserved anymore, the open proposal still is
+    env.ledger().with_mut(|li| li.sequence_number = 1_001);
+    assert_eq!(
+        multisig
+            .query_treasury(&vec![&env, token.address.clone()])
+            .get(0)
+            .unwrap()
+            .reserved,
+        6_000
+    );
+}
@@ -0,0 +1,191 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+use super::setup::{
+    deploy_funded_token_contract, deploy_token_contract, initialize_multisig_contract,
+    update_config,
+};
+use crate::{
+    error::ContractError,
+    storage::{ApprovalStage, ConfigUpdate, ProposalStatus},
+};
+
+#[test]
+fn proposal_passes_stages_in_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let lead1 = Address::generate(&env);
+    let lead2 = Address::generate(&env);
+    let treasurer1 = Address::generate(&env);
+    let treasurer2 = Address::generate(&env);
+    let members = vec![
+        &env,
+        lead1.clone(),
+        lead2.clone(),
+        treasurer1.clone(),
+        treasurer2.clone(),
+    ];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let stages = vec![
+        &env,
+        ApprovalStage {
+            name: String::from_str(&env, "Team leads"),
+            signers: vec![&env, lead1.clone(), lead2.clone()],
+            quorum_bps: 5_000,
+        },
+        ApprovalStage {
+            name: String::from_str(&env, "Treasury committee"),
+            signers: vec![&env, treasurer1.clone(), treasurer2.clone()],
+            quorum_bps: 10_000,
+        },
+    ];
+    update_config(
+        &multisig,
+        &members,
+        ConfigUpdate::ApprovalStages(stages.clone()),
+    );
+
+    let token = deploy_token_contract(&env, &lead1);
+    token.mint(&multisig.address, &10_000);
+    let recipient = Address::generate(&env);
+
+    multisig.create_transaction_proposal(
+        &lead1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &recipient,
+        &10_000,
+        &token.address,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+    let proposal = multisig.query_proposal(&proposal_id);
+    assert_eq!(proposal.stages, stages);
+    assert_eq!(proposal.current_stage, 0);
+
+    // treasury committee can't sign before the team leads approve
+    assert_eq!(
+        multisig.try_sign_proposal(&treasurer1, &proposal_id),
+        Err(Ok(ContractError::SignerNotInCurrentStage))
+    );
+
+    // one out of two team leads is enough to pass the first stage
+    multisig.sign_proposal(&lead1, &proposal_id);
+    assert_eq!(multisig.query_proposal(&proposal_id).current_stage, 1);
+    assert_eq!(
+        multisig.try_execute_proposal(&lead1, &proposal_id),
+        Err(Ok(ContractError::QuorumNotReached))
+    );
+
+    // first stage is already passed
+    assert_eq!(
+        multisig.try_sign_proposal(&lead2, &proposal_id),
+        Err(Ok(ContractError::SignerNotInCurrentStage))
+    );
+
+    multisig.sign_proposal(&treasurer1, &proposal_id);
+    assert_eq!(multisig.query_proposal(&proposal_id).current_stage, 1);
+    assert!(!multisig.is_proposal_ready(&proposal_id));
+
+    multisig.sign_proposal(&treasurer2, &proposal_id);
+    assert_eq!(multisig.query_proposal(&proposal_id).current_stage, 2);
+    assert!(multisig.is_proposal_ready(&proposal_id));
+
+    multisig.execute_proposal(&treasurer2, &proposal_id);
+    assert_eq!(token.balance(&recipient), 10_000i128);
+    assert_eq!(
+        multisig.query_proposal(&proposal_id).status,
+        ProposalStatus::Closed
+    );
+}
+
+#[test]
+fn proposals_without_workflow_have_no_stages() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &Address::generate(&env),
+        &10_000,
+        &deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address,
+        &None,
+    );
+
+    let proposal = multisig.query_proposal(&1);
+    assert_eq!(proposal.stages, vec![&env]);
+    assert_eq!(proposal.current_stage, 0);
+}
+
+#[test]
+fn invalid_approval_stages() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let create_stages_proposal = |signers: Vec<Address>, quorum_bps: u32| {
+        multisig.try_create_config_proposal(
+            &member1,
+            &String::from_str(&env, "Approval workflow"),
+            &String::from_str(&env, "Description"),
+            &ConfigUpdate::ApprovalStages(vec![
+                &env,
+                ApprovalStage {
+                    name: String::from_str(&env, "Stage"),
+                    signers,
+                    quorum_bps,
+                },
+            ]),
+            &None,
+        )
+    };
+
+    assert_eq!(
+        create_stages_proposal(vec![&env], 5_000),
+        Err(Ok(ContractError::InvalidApprovalStage))
+    );
+    assert_eq!(
+        create_stages_proposal(vec![&env, member1.clone(), Address::generate(&env)], 5_000),
+        Err(Ok(ContractError::UnauthorizedNotAMember))
+    );
+    assert_eq!(
+        create_stages_proposal(vec![&env, member1.clone()], 100),
+        Err(Ok(ContractError::InvalidApprovalStage))
+    );
+    assert_eq!(
+        create_stages_proposal(vec![&env, member1.clone()], 10_001),
+        Err(Ok(ContractError::InvalidApprovalStage))
+    );
+}
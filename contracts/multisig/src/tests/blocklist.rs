@@ -0,0 +1,180 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, String,
+};
+
+use super::setup::{
+    deploy_token_contract, initialize_multisig_contract, sign_and_execute_proposal,
+};
+use crate::{
+    contract::MultisigClient,
+    error::ContractError,
+    storage::{BlocklistUpdate, ProposalStatus},
+};
+
+fn update_blocklist(
+    env: &Env,
+    multisig: &MultisigClient,
+    member: &Address,
+    update: BlocklistUpdate,
+) {
+    multisig.create_blocklist_proposal(
+        member,
+        &String::from_str(env, "Blocklist"),
+        &String::from_str(env, "Update the blocklist"),
+        &update,
+        &None,
+    );
+    sign_and_execute_proposal(
+        multisig,
+        &vec![env, member.clone()],
+        multisig.query_last_proposal_id(),
+    );
+}
+
+#[test]
+fn blocked_recipient_cannot_be_paid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+    let sanctioned = Address::generate(&env);
+
+    update_blocklist(
+        &env,
+        &multisig,
+        &member1,
+        BlocklistUpdate::Add(sanctioned.clone()),
+    );
+    assert_eq!(multisig.query_blocklist(), vec![&env, sanctioned.clone()]);
+
+    assert_eq!(
+        multisig.try_create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &sanctioned,
+            &10_000,
+            &token.address,
+            &None,
+        ),
+        Err(Ok(ContractError::RecipientBlocked))
+    );
+}
+
+#[test]
+fn recipient_blocked_after_proposal_creation_cannot_be_paid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+    let compromised = Address::generate(&env);
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &compromised,
+        &10_000,
+        &token.address,
+        &None,
+    );
+    let transaction_proposal_id = multisig.query_last_proposal_id();
+    multisig.sign_proposal(&member1, &transaction_proposal_id);
+
+    update_blocklist(
+        &env,
+        &multisig,
+        &member1,
+        BlocklistUpdate::Add(compromised.clone()),
+    );
+
+    // quorum is reached, but the recipient is on the blocklist now
+    multisig.execute_proposal(&member1, &transaction_proposal_id);
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                multisig.address.clone(),
+                (
+                    String::from_str(&env, "Multisig"),
+                    String::from_str(&env, "Blocked recipient")
+                )
+                    .into_val(&env),
+                (transaction_proposal_id, compromised.clone()).into_val(&env),
+            )
+        ]
+    );
+    assert_eq!(
+        multisig.query_proposal(&transaction_proposal_id).status,
+        ProposalStatus::Rejected
+    );
+    assert_eq!(token.balance(&compromised), 0i128);
+
+    // the proposal stays rejected once the address is removed from the blocklist
+    update_blocklist(
+        &env,
+        &multisig,
+        &member1,
+        BlocklistUpdate::Remove(compromised.clone()),
+    );
+    assert_eq!(multisig.query_blocklist(), vec![&env]);
+
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &transaction_proposal_id),
+        Err(Ok(ContractError::ProposalClosed))
+    );
+    assert_eq!(token.balance(&compromised), 0i128);
+}
+
+#[test]
+fn remove_address_that_is_not_blocked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        vec![&env, member1.clone()],
+        None,
+    );
+
+    assert_eq!(
+        multisig.try_create_blocklist_proposal(
+            &member1,
+            &String::from_str(&env, "Blocklist"),
+            &String::from_str(&env, "Remove address"),
+            &BlocklistUpdate::Remove(Address::generate(&env)),
+            &None,
+        ),
+        Err(Ok(ContractError::AddressNotBlocked))
+    );
+}
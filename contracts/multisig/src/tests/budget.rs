@@ -0,0 +1,394 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+use super::setup::{
+    deploy_token_contract, initialize_multisig_contract, update_config, DAY_AS_TIMESTAMP,
+};
+use crate::{
+    error::ContractError,
+    storage::{Budget, BudgetPeriod, BudgetStatus, ConfigUpdate},
+    ONE_HOUR,
+};
+
+const MONTH_AS_TIMESTAMP: u64 = DAY_AS_TIMESTAMP * 30;
+
+#[test]
+fn transactions_are_limited_by_the_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &1_000_000);
+
+    let budget = Budget {
+        token: token.address.clone(),
+        amount: 100_000,
+        period: BudgetPeriod::Seconds(MONTH_AS_TIMESTAMP),
+    };
+    update_config(&multisig, &members, ConfigUpdate::Budget(budget.clone()));
+
+    let transfer = |amount: i128| {
+        multisig.create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &Address::generate(&env),
+            &amount,
+            &token.address,
+            &None,
+        );
+        let proposal_id = multisig.query_last_proposal_id();
+        multisig.sign_proposal(&member1, &proposal_id);
+        multisig.try_execute_proposal(&member1, &proposal_id)
+    };
+
+    env.ledger().with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP);
+    transfer(60_000).unwrap().unwrap();
+    transfer(40_000).unwrap().unwrap();
+    assert_eq!(transfer(1), Err(Ok(ContractError::BudgetExceeded)));
+    assert_eq!(
+        multisig.query_budgets(),
+        vec![
+            &env,
+            BudgetStatus {
+                budget: budget.clone(),
+                period_start: 0,
+                spent: 100_000,
+                remaining: 0,
+            }
+        ]
+    );
+
+    // the budget is renewed in the next period
+    env.ledger()
+        .with_mut(|li| li.timestamp = MONTH_AS_TIMESTAMP + DAY_AS_TIMESTAMP);
+    assert_eq!(multisig.query_budgets().get(0).unwrap().remaining, 100_000);
+    transfer(30_000).unwrap().unwrap();
+    assert_eq!(
+        multisig.query_budgets(),
+        vec![
+            &env,
+            BudgetStatus {
+                budget,
+                period_start: MONTH_AS_TIMESTAMP,
+                spent: 30_000,
+                remaining: 70_000,
+            }
+        ]
+    );
+
+    update_config(
+        &multisig,
+        &members,
+        ConfigUpdate::RemoveBudget(token.address.clone()),
+    );
+    assert_eq!(multisig.query_budgets(), vec![&env]);
+    transfer(200_000).unwrap().unwrap();
+}
+
+#[test]
+fn claimable_transactions_are_charged_on_execution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &1_000_000);
+
+    update_config(
+        &multisig,
+        &members,
+        ConfigUpdate::Budget(Budget {
+            token: token.address.clone(),
+            amount: 100_000,
+            period: BudgetPeriod::Seconds(MONTH_AS_TIMESTAMP),
+        }),
+    );
+
+    let recipient = Address::generate(&env);
+    let claimable_transfer = |amount: i128| {
+        multisig.create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &recipient,
+            &amount,
+            &token.address,
+            &None,
+        );
+        let proposal_id = multisig.query_last_proposal_id();
+        multisig.set_claimable(&member1, &proposal_id, &Some(DAY_AS_TIMESTAMP));
+        multisig.sign_proposal(&member1, &proposal_id);
+        (
+            proposal_id,
+            multisig.try_execute_proposal(&member1, &proposal_id),
+        )
+    };
+
+    env.ledger().with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP);
+    let (proposal_id, result) = claimable_transfer(70_000);
+    result.unwrap().unwrap();
+    assert_eq!(multisig.query_budgets().get(0).unwrap().spent, 70_000);
+
+    // the budget is used up before anything is claimed
+    let (_, result) = claimable_transfer(30_001);
+    assert_eq!(result, Err(Ok(ContractError::BudgetExceeded)));
+
+    // claiming doesn't charge the budget again
+    multisig.claim(&proposal_id);
+    assert_eq!(token.balance(&recipient), 70_000);
+    assert_eq!(multisig.query_budgets().get(0).unwrap().spent, 70_000);
+}
+
+#[test]
+fn calendar_month_budget_renews_on_the_first_day_of_the_month() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &1_000_000);
+
+    update_config(
+        &multisig,
+        &members,
+        ConfigUpdate::Budget(Budget {
+            token: token.address.clone(),
+            amount: 100_000,
+            period: BudgetPeriod::CalendarMonth,
+        }),
+    );
+
+    let transfer = |amount: i128| {
+        multisig.create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &Address::generate(&env),
+            &amount,
+            &token.address,
+            &None,
+        );
+        let proposal_id = multisig.query_last_proposal_id();
+        multisig.sign_proposal(&member1, &proposal_id);
+        multisig.try_execute_proposal(&member1, &proposal_id)
+    };
+
+    // 2024-01-01T00:00:00Z, 2024-02-01T00:00:00Z and 2024-03-01T00:00:00Z
+    const JANUARY_2024: u64 = 1_704_067_200;
+    const FEBRUARY_2024: u64 = 1_706_745_600;
+    const MARCH_2024: u64 = 1_709_251_200;
+
+    // the last hour of January still belongs to it
+    env.ledger()
+        .with_mut(|li| li.timestamp = FEBRUARY_2024 - ONE_HOUR);
+    transfer(100_000).unwrap().unwrap();
+    let status = multisig.query_budgets().get(0).unwrap();
+    assert_eq!(status.period_start, JANUARY_2024);
+    assert_eq!(status.remaining, 0);
+    assert_eq!(transfer(1), Err(Ok(ContractError::BudgetExceeded)));
+
+    // February is 29 days long in a leap year
+    env.ledger().with_mut(|li| li.timestamp = FEBRUARY_2024);
+    let status = multisig.query_budgets().get(0).unwrap();
+    assert_eq!(status.period_start, FEBRUARY_2024);
+    assert_eq!(status.remaining, 100_000);
+    transfer(100_000).unwrap().unwrap();
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = FEBRUARY_2024 + 28 * DAY_AS_TIMESTAMP);
+    assert_eq!(transfer(1), Err(Ok(ContractError::BudgetExceeded)));
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = FEBRUARY_2024 + 29 * DAY_AS_TIMESTAMP);
+    let status = multisig.query_budgets().get(0).unwrap();
+    assert_eq!(status.period_start, MARCH_2024);
+    assert_eq!(status.remaining, 100_000);
+}
+
+#[test]
+fn budget_exceeding_proposal_needs_higher_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let member4 = Address::generate(&env);
+    let members = vec![
+        &env,
+        member1.clone(),
+        member2.clone(),
+        member3.clone(),
+        member4.clone(),
+    ];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        5_000u32,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &1_000_000);
+
+    update_config(
+        &multisig,
+        &members,
+        ConfigUpdate::Budget(Budget {
+            token: token.address.clone(),
+            amount: 100_000,
+            period: BudgetPeriod::Seconds(MONTH_AS_TIMESTAMP),
+        }),
+    );
+    update_config(
+        &multisig,
+        &members,
+        ConfigUpdate::BudgetExceedingQuorumBps(7_500),
+    );
+
+    let recipient = Address::generate(&env);
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "Emergency"),
+        &String::from_str(&env, "Over the monthly budget"),
+        &recipient,
+        &150_000,
+        &token.address,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+
+    // only the creator can mark it
+    assert_eq!(
+        multisig.try_set_budget_exceeding(&member2, &proposal_id, &true),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert!(!multisig.query_budget_exceeding(&proposal_id));
+    multisig.set_budget_exceeding(&member1, &proposal_id, &true);
+    assert!(multisig.query_budget_exceeding(&proposal_id));
+
+    multisig.sign_proposal(&member1, &proposal_id);
+    multisig.sign_proposal(&member2, &proposal_id);
+
+    // marking can't be changed after signing started
+    assert_eq!(
+        multisig.try_set_budget_exceeding(&member1, &proposal_id, &false),
+        Err(Ok(ContractError::ProposalAlreadySigned))
+    );
+
+    // regular quorum is reached, budget-exceeding quorum isn't
+    assert!(!multisig.is_proposal_ready(&proposal_id));
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::QuorumNotReached))
+    );
+
+    multisig.sign_proposal(&member3, &proposal_id);
+    multisig.execute_proposal(&member1, &proposal_id);
+    assert_eq!(token.balance(&recipient), 150_000);
+    assert_eq!(multisig.query_budgets().get(0).unwrap().remaining, -50_000);
+
+    // unmarked proposal over the budget is refused even with all signatures
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#02"),
+        &String::from_str(&env, "TxTestDescription"),
+        &recipient,
+        &1_000,
+        &token.address,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+    for member in members.iter() {
+        multisig.sign_proposal(&member, &proposal_id);
+    }
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::BudgetExceeded))
+    );
+
+    // only proposals that spend from a budget can be marked
+    multisig.create_config_proposal(
+        &member1,
+        &String::from_str(&env, "Quorum"),
+        &String::from_str(&env, "Description"),
+        &ConfigUpdate::BudgetExceedingQuorumBps(10_000),
+        &None,
+    );
+    assert_eq!(
+        multisig.try_set_budget_exceeding(&member1, &multisig.query_last_proposal_id(), &true),
+        Err(Ok(ContractError::InvalidAmendment))
+    );
+}
+
+#[test]
+fn invalid_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        vec![&env, member1.clone()],
+        None,
+    );
+    let token = deploy_token_contract(&env, &member1);
+
+    for (amount, period) in [
+        (0, BudgetPeriod::Seconds(MONTH_AS_TIMESTAMP)),
+        (100_000, BudgetPeriod::Seconds(0)),
+    ] {
+        assert_eq!(
+            multisig.try_create_config_proposal(
+                &member1,
+                &String::from_str(&env, "Budget"),
+                &String::from_str(&env, "Description"),
+                &ConfigUpdate::Budget(Budget {
+                    token: token.address.clone(),
+                    amount,
+                    period,
+                }),
+                &None,
+            ),
+            Err(Ok(ContractError::InvalidAmount))
+        );
+    }
+}
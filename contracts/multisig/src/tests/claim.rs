@@ -0,0 +1,175 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+use super::setup::{
+    deploy_token_contract, initialize_multisig_contract, sign_and_execute_proposal,
+    DAY_AS_TIMESTAMP,
+};
+use crate::{
+    error::ContractError,
+    storage::{Claim, ConfigUpdate, SignerRoles},
+};
+
+#[test]
+fn recipient_claims_executed_transaction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let recipient = Address::generate(&env);
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &recipient,
+        &6_000,
+        &token.address,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+
+    // only the creator can make it claimable
+    assert_eq!(
+        multisig.try_set_claimable(&member2, &proposal_id, &Some(DAY_AS_TIMESTAMP)),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(multisig.query_claim_window(&proposal_id), None);
+    for claim_window in [0, u64::MAX] {
+        assert_eq!(
+            multisig.try_set_claimable(&member1, &proposal_id, &Some(claim_window)),
+            Err(Ok(ContractError::InvalidExpirationDate))
+        );
+    }
+    multisig.set_claimable(&member1, &proposal_id, &Some(DAY_AS_TIMESTAMP));
+    assert_eq!(
+        multisig.query_claim_window(&proposal_id),
+        Some(DAY_AS_TIMESTAMP)
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP);
+    sign_and_execute_proposal(&multisig, &members, proposal_id);
+    assert_eq!(token.balance(&recipient), 0);
+    assert_eq!(
+        multisig.query_claim(&proposal_id),
+        Claim {
+            proposal_id,
+            recipient: recipient.clone(),
+            token: token.address.clone(),
+            amount: 6_000,
+            deadline: DAY_AS_TIMESTAMP * 2,
+        }
+    );
+
+    // the claimable amount stays reserved
+    assert_eq!(
+        multisig.try_create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#02"),
+            &String::from_str(&env, "TxTestDescription"),
+            &recipient,
+            &4_001,
+            &token.address,
+            &None,
+        ),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP * 2);
+    multisig.claim(&proposal_id);
+    assert_eq!(token.balance(&recipient), 6_000);
+    assert_eq!(
+        multisig.try_claim(&proposal_id),
+        Err(Ok(ContractError::ClaimNotFound))
+    );
+
+    // only transactions can be made claimable
+    multisig.create_config_proposal(
+        &member1,
+        &String::from_str(&env, "Roles"),
+        &String::from_str(&env, "Description"),
+        &ConfigUpdate::SignerRoles(SignerRoles {
+            mandatory_signers: vec![&env],
+            veto_holders: vec![&env],
+        }),
+        &None,
+    );
+    assert_eq!(
+        multisig.try_set_claimable(
+            &member1,
+            &multisig.query_last_proposal_id(),
+            &Some(DAY_AS_TIMESTAMP)
+        ),
+        Err(Ok(ContractError::InvalidAmendment))
+    );
+}
+
+#[test]
+fn expired_claim_returns_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let recipient = Address::generate(&env);
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &recipient,
+        &10_000,
+        &token.address,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+    multisig.set_claimable(&member1, &proposal_id, &Some(DAY_AS_TIMESTAMP));
+    sign_and_execute_proposal(&multisig, &members, proposal_id);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP + 1);
+    assert_eq!(
+        multisig.try_claim(&proposal_id),
+        Err(Ok(ContractError::ClaimExpired))
+    );
+
+    // the funds can be used again
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#02"),
+        &String::from_str(&env, "TxTestDescription"),
+        &recipient,
+        &10_000,
+        &token.address,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(token.balance(&recipient), 10_000);
+}
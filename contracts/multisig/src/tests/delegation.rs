@@ -0,0 +1,283 @@
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, Map, String,
+};
+
+use super::setup::{
+    deploy_funded_token_contract, deploy_token_contract, initialize_multisig_contract,
+    DAY_AS_TIMESTAMP,
+};
+use crate::{
+    error::ContractError,
+    storage::{DataKey, Delegation, ProposalStatus},
+};
+
+#[test]
+fn delegate_signs_on_behalf_of_member() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+    let recipient = Address::generate(&env);
+
+    multisig.delegate_signing(&member2, &delegate, &DAY_AS_TIMESTAMP);
+    assert_eq!(
+        multisig.query_delegations(),
+        vec![
+            &env,
+            Delegation {
+                member: member2.clone(),
+                delegate: delegate.clone(),
+                expiration_timestamp: DAY_AS_TIMESTAMP,
+            }
+        ]
+    );
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &recipient,
+        &10_000,
+        &token.address,
+        &None,
+    );
+
+    multisig.sign_proposal(&member1, &1);
+    multisig.sign_proposal(&delegate, &1);
+
+    // signature of the delegate is counted for the member that delegated
+    assert_eq!(
+        multisig.query_signatures(&1),
+        vec![
+            &env,
+            (member1.clone(), true, None),
+            (member2.clone(), true, Some(delegate.clone())),
+        ]
+    );
+
+    multisig.execute_proposal(&member1, &1);
+    assert_eq!(token.balance(&recipient), 10_000i128);
+    assert_eq!(multisig.query_proposal(&1).status, ProposalStatus::Closed);
+}
+
+#[test]
+fn member_signature_is_not_overwritten_by_delegate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    // member1 is on vacation, member2 signs for both of them
+    multisig.delegate_signing(&member1, &member2, &DAY_AS_TIMESTAMP);
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &Address::generate(&env),
+        &10_000,
+        &deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address,
+        &None,
+    );
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#02"),
+        &String::from_str(&env, "TxTestDescription"),
+        &Address::generate(&env),
+        &10_000,
+        &deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address,
+        &None,
+    );
+
+    multisig.sign_proposal(&member2, &1);
+    assert_eq!(
+        multisig.query_signatures(&1),
+        vec![
+            &env,
+            (member1.clone(), true, Some(member2.clone())),
+            (member2.clone(), true, None),
+        ]
+    );
+
+    multisig.sign_proposal(&member1, &2);
+    multisig.sign_proposal(&member2, &2);
+    assert_eq!(
+        multisig.query_signatures(&2),
+        vec![
+            &env,
+            (member1.clone(), true, None),
+            (member2.clone(), true, None),
+        ]
+    );
+}
+
+#[test]
+fn expired_or_revoked_delegation_cannot_sign() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &Address::generate(&env),
+        &10_000,
+        &deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address,
+        &None,
+    );
+
+    multisig.delegate_signing(&member2, &delegate, &DAY_AS_TIMESTAMP);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP + 1);
+    assert_eq!(multisig.query_delegations(), vec![&env]);
+    assert_eq!(
+        multisig.try_sign_proposal(&delegate, &1),
+        Err(Ok(ContractError::UnauthorizedNotAMember))
+    );
+
+    multisig.delegate_signing(&member2, &delegate, &(DAY_AS_TIMESTAMP * 2));
+    multisig.revoke_delegation(&member2);
+    assert_eq!(
+        multisig.try_sign_proposal(&delegate, &1),
+        Err(Ok(ContractError::UnauthorizedNotAMember))
+    );
+    assert_eq!(
+        multisig.try_revoke_delegation(&member2),
+        Err(Ok(ContractError::DelegationNotFound))
+    );
+}
+
+#[test]
+fn delegate_signing_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let not_a_member = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    assert_eq!(
+        multisig.try_delegate_signing(&not_a_member, &member1, &DAY_AS_TIMESTAMP),
+        Err(Ok(ContractError::UnauthorizedNotAMember))
+    );
+    assert_eq!(
+        multisig.try_delegate_signing(&member1, &member1, &DAY_AS_TIMESTAMP),
+        Err(Ok(ContractError::InvalidDelegation))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP);
+    assert_eq!(
+        multisig.try_delegate_signing(&member1, &not_a_member, &DAY_AS_TIMESTAMP),
+        Err(Ok(ContractError::InvalidDelegation))
+    );
+}
+
+#[test]
+fn signatures_made_before_delegations_are_read() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+    let recipient = Address::generate(&env);
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &recipient,
+        &10_000,
+        &token.address,
+        &None,
+    );
+
+    // signature in the layout written before delegations existed
+    env.as_contract(&multisig.address, || {
+        let legacy_signatures: Map<Address, ()> = map![&env, (member1.clone(), ())];
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposalSignatures(1), &legacy_signatures);
+    });
+    assert_eq!(
+        multisig.query_signatures(&1),
+        vec![
+            &env,
+            (member1.clone(), true, None),
+            (member2.clone(), false, None),
+        ]
+    );
+
+    multisig.delegate_signing(&member2, &delegate, &DAY_AS_TIMESTAMP);
+    multisig.sign_proposal(&delegate, &1);
+    assert_eq!(
+        multisig.query_signatures(&1),
+        vec![
+            &env,
+            (member1.clone(), true, None),
+            (member2.clone(), true, Some(delegate.clone())),
+        ]
+    );
+
+    multisig.execute_proposal(&member1, &1);
+    assert_eq!(token.balance(&recipient), 10_000i128);
+}
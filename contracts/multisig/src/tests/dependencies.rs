@@ -0,0 +1,225 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+use super::setup::{
+    create_transaction, deploy_token_contract, initialize_multisig_contract,
+    sign_and_execute_proposal, update_config, DAY_AS_TIMESTAMP,
+};
+use crate::{
+    error::ContractError,
+    storage::{ConfigUpdate, ProposalStatus, SignerRoles},
+    MAX_SEQUENCE_STEPS,
+};
+
+#[test]
+fn dependent_proposal_waits_for_its_dependencies() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let first = create_transaction(&env, &multisig, &member1, &token);
+    let second = create_transaction(&env, &multisig, &member1, &token);
+    let third = create_transaction(&env, &multisig, &member1, &token);
+    multisig.set_dependencies(&member1, &third, &vec![&env, first, second]);
+    assert_eq!(
+        multisig.query_dependencies(&third),
+        vec![&env, first, second]
+    );
+
+    multisig.sign_proposal(&member1, &third);
+    assert!(!multisig.is_proposal_ready(&third));
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &third),
+        Err(Ok(ContractError::DependencyNotExecuted))
+    );
+    sign_and_execute_proposal(&multisig, &members, first);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &third),
+        Err(Ok(ContractError::DependencyNotExecuted))
+    );
+    sign_and_execute_proposal(&multisig, &members, second);
+    assert!(multisig.is_proposal_ready(&third));
+    multisig.execute_proposal(&member1, &third);
+
+    // dependencies have to be earlier proposals that can still be executed
+    let fourth = create_transaction(&env, &multisig, &member1, &token);
+    let fifth = create_transaction(&env, &multisig, &member1, &token);
+    multisig.cancel_proposal(&member1, &fifth);
+    for dependencies in [
+        vec![&env, fourth],
+        vec![&env, fourth + 2],
+        vec![&env, fifth],
+    ] {
+        assert_eq!(
+            multisig.try_set_dependencies(&member1, &fourth, &dependencies),
+            Err(Ok(ContractError::InvalidDependency))
+        );
+    }
+}
+
+#[test]
+fn rejected_or_cancelled_dependency_cancels_dependents() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+    update_config(
+        &multisig,
+        &members,
+        ConfigUpdate::SignerRoles(SignerRoles {
+            mandatory_signers: vec![&env],
+            veto_holders: vec![&env, member2.clone()],
+        }),
+    );
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let first = create_transaction(&env, &multisig, &member1, &token);
+    let second = create_transaction(&env, &multisig, &member1, &token);
+    let third = create_transaction(&env, &multisig, &member1, &token);
+    multisig.set_dependencies(&member1, &second, &vec![&env, first]);
+    multisig.set_dependencies(&member1, &third, &vec![&env, second]);
+
+    // only the creator can cancel
+    assert_eq!(
+        multisig.try_cancel_proposal(&member2, &first),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    multisig.cancel_proposal(&member1, &first);
+    for proposal_id in [first, second, third] {
+        assert_eq!(
+            multisig.query_proposal(&proposal_id).status,
+            ProposalStatus::Cancelled
+        );
+    }
+    assert_eq!(
+        multisig.try_cancel_proposal(&member1, &first),
+        Err(Ok(ContractError::ProposalClosed))
+    );
+
+    let fourth = create_transaction(&env, &multisig, &member1, &token);
+    let fifth = create_transaction(&env, &multisig, &member1, &token);
+    multisig.set_dependencies(&member1, &fifth, &vec![&env, fourth]);
+    multisig.reject_proposal(&member2, &fourth);
+    assert_eq!(
+        multisig.query_proposal(&fifth).status,
+        ProposalStatus::Cancelled
+    );
+    assert_eq!(
+        multisig.try_sign_proposal(&member1, &fifth),
+        Err(Ok(ContractError::ProposalClosed))
+    );
+}
+
+#[test]
+fn strict_sequence_executes_in_creation_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+    update_config(&multisig, &members, ConfigUpdate::StrictSequence(true));
+    assert!(multisig.query_strict_sequence());
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let first = create_transaction(&env, &multisig, &member1, &token);
+    let second = create_transaction(&env, &multisig, &member1, &token);
+    let third = create_transaction(&env, &multisig, &member1, &token);
+
+    multisig.sign_proposal(&member1, &second);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &second),
+        Err(Ok(ContractError::ProposalOutOfOrder))
+    );
+    sign_and_execute_proposal(&multisig, &members, first);
+    multisig.execute_proposal(&member1, &second);
+
+    // cancelled proposals don't block the sequence
+    let fourth = create_transaction(&env, &multisig, &member1, &token);
+    multisig.cancel_proposal(&member1, &third);
+    sign_and_execute_proposal(&multisig, &members, fourth);
+
+    // neither do expired ones
+    let fifth = create_transaction(&env, &multisig, &member1, &token);
+    env.ledger()
+        .with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP * 8);
+    let sixth = create_transaction(&env, &multisig, &member1, &token);
+    multisig.sign_proposal(&member1, &sixth);
+    multisig.execute_proposal(&member1, &sixth);
+    assert_eq!(multisig.query_proposal(&fifth).status, ProposalStatus::Open);
+}
+
+#[test]
+fn long_settled_history_is_stepped_over_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    // without strict sequence mode nothing holds back the next proposal
+    for _ in 0..(MAX_SEQUENCE_STEPS + 5) {
+        let proposal_id = create_transaction(&env, &multisig, &member1, &token);
+        multisig.cancel_proposal(&member1, &proposal_id);
+    }
+    let last_cancelled = multisig.query_last_proposal_id();
+    assert_eq!(multisig.advance_sequence(&member1, &1), last_cancelled + 1);
+
+    update_config(&multisig, &members, ConfigUpdate::StrictSequence(true));
+
+    // each execution steps over a limited number of settled proposals
+    let proposal_id = create_transaction(&env, &multisig, &member1, &token);
+    multisig.sign_proposal(&member1, &proposal_id);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::ProposalOutOfOrder))
+    );
+
+    // the rest of the history is stepped over in batches
+    assert_eq!(multisig.advance_sequence(&member1, &10), 11);
+    multisig.execute_proposal(&member1, &proposal_id);
+}
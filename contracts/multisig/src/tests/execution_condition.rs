@@ -0,0 +1,227 @@
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, vec, Address, Env, IntoVal,
+    String,
+};
+
+use super::setup::{
+    create_transaction, deploy_token_contract, initialize_multisig_contract,
+    sign_and_execute_proposal,
+};
+use crate::{
+    error::ContractError,
+    storage::{
+        AddressBookEntry, AddressBookKind, AddressBookUpdate, ExecutionCondition, PriceBound,
+        ViewCall,
+    },
+};
+
+// Stand-in for a price oracle
+#[contract]
+pub struct MockPriceOracle;
+
+#[contractimpl]
+impl MockPriceOracle {
+    pub fn set_price(env: Env, asset: Address, price: i128) {
+        env.storage().persistent().set(&asset, &price);
+    }
+
+    pub fn lastprice(env: Env, asset: Address) -> Option<i128> {
+        env.storage().persistent().get(&asset)
+    }
+
+    pub fn has_price(env: Env, asset: Address) -> bool {
+        env.storage().persistent().has(&asset)
+    }
+}
+
+#[test]
+fn price_condition_is_checked_on_execution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        vec![&env, member1.clone(), member2.clone()],
+        None,
+    );
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let oracle = MockPriceOracleClient::new(&env, &env.register_contract(None, MockPriceOracle {}));
+    let asset = Address::generate(&env);
+
+    let proposal_id = create_transaction(&env, &multisig, &member1, &token);
+    let condition = ExecutionCondition::PriceBelow(PriceBound {
+        oracle: oracle.address.clone(),
+        asset: asset.clone(),
+        bound: 100,
+    });
+    assert_eq!(
+        multisig.try_set_execution_condition(&member2, &proposal_id, &Some(condition.clone())),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    multisig.set_execution_condition(&member1, &proposal_id, &Some(condition));
+
+    multisig.sign_proposal(&member1, &proposal_id);
+    multisig.sign_proposal(&member2, &proposal_id);
+    assert_eq!(
+        multisig.try_set_execution_condition(&member1, &proposal_id, &None),
+        Err(Ok(ContractError::ProposalAlreadySigned))
+    );
+
+    // no price and a price above the bound don't meet the condition
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::ConditionNotMet))
+    );
+    oracle.set_price(&asset, &100);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::ConditionNotMet))
+    );
+
+    oracle.set_price(&asset, &99);
+    multisig.execute_proposal(&member1, &proposal_id);
+    assert_eq!(token.balance(&multisig.address), 9_000);
+
+    // a price above the bound
+    let proposal_id = create_transaction(&env, &multisig, &member1, &token);
+    multisig.set_execution_condition(
+        &member1,
+        &proposal_id,
+        &Some(ExecutionCondition::PriceAbove(PriceBound {
+            oracle: oracle.address.clone(),
+            asset: asset.clone(),
+            bound: 99,
+        })),
+    );
+    multisig.sign_proposal(&member1, &proposal_id);
+    multisig.sign_proposal(&member2, &proposal_id);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::ConditionNotMet))
+    );
+    oracle.set_price(&asset, &120);
+    multisig.execute_proposal(&member1, &proposal_id);
+}
+
+#[test]
+fn view_call_condition_has_to_return_true() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        vec![&env, member1.clone()],
+        None,
+    );
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let oracle = MockPriceOracleClient::new(&env, &env.register_contract(None, MockPriceOracle {}));
+    let asset = Address::generate(&env);
+
+    let condition = ExecutionCondition::ViewCall(ViewCall {
+        contract: oracle.address.clone(),
+        function: symbol_short!("has_price"),
+        args: vec![&env, asset.into_val(&env)],
+    });
+    let proposal_id = create_transaction(&env, &multisig, &member1, &token);
+
+    // only contracts approved in the address book can be called
+    assert_eq!(
+        multisig.try_set_execution_condition(&member1, &proposal_id, &Some(condition.clone())),
+        Err(Ok(ContractError::AddressNotInAddressBook))
+    );
+    for (address, label) in [
+        (oracle.address.clone(), "Price oracle"),
+        (token.address.clone(), "USDC"),
+    ] {
+        multisig.create_address_book_proposal(
+            &member1,
+            &String::from_str(&env, "Address book"),
+            &String::from_str(&env, "Contract used by execution conditions"),
+            &AddressBookUpdate::Add(AddressBookEntry {
+                address,
+                kind: AddressBookKind::Contract,
+                label: String::from_str(&env, label),
+            }),
+            &None,
+        );
+        sign_and_execute_proposal(
+            &multisig,
+            &vec![&env, member1.clone()],
+            multisig.query_last_proposal_id(),
+        );
+    }
+
+    // the multisig's tokens can never be called, even when listed
+    multisig.create_address_book_proposal(
+        &member1,
+        &String::from_str(&env, "Address book"),
+        &String::from_str(&env, "Treasury token"),
+        &AddressBookUpdate::Add(AddressBookEntry {
+            address: token.address.clone(),
+            kind: AddressBookKind::Token,
+            label: String::from_str(&env, "USDC"),
+        }),
+        &None,
+    );
+    sign_and_execute_proposal(
+        &multisig,
+        &vec![&env, member1.clone()],
+        multisig.query_last_proposal_id(),
+    );
+    assert_eq!(
+        multisig.try_set_execution_condition(
+            &member1,
+            &proposal_id,
+            &Some(ExecutionCondition::ViewCall(ViewCall {
+                contract: token.address.clone(),
+                function: symbol_short!("decimals"),
+                args: vec![&env],
+            }))
+        ),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    multisig.set_execution_condition(&member1, &proposal_id, &Some(condition.clone()));
+    assert_eq!(
+        multisig.query_execution_condition(&proposal_id),
+        Some(condition)
+    );
+    multisig.sign_proposal(&member1, &proposal_id);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::ConditionNotMet))
+    );
+
+    oracle.set_price(&asset, &1);
+    multisig.execute_proposal(&member1, &proposal_id);
+
+    // a function that doesn't exist never meets the condition
+    let proposal_id = create_transaction(&env, &multisig, &member1, &token);
+    multisig.set_execution_condition(
+        &member1,
+        &proposal_id,
+        &Some(ExecutionCondition::ViewCall(ViewCall {
+            contract: oracle.address.clone(),
+            function: symbol_short!("unknown"),
+            args: vec![&env],
+        })),
+    );
+    multisig.sign_proposal(&member1, &proposal_id);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::ConditionNotMet))
+    );
+}
@@ -31,6 +31,7 @@ fn initialize_multisig() {
         members: members.clone(),
         quorum_bps: 10_000u32,
         version_proposal: 0u32,
+        upgrade_history: vec![&env],
     };
     assert_eq!(multisig.query_multisig_info(), expected_response);
     assert_eq!(multisig.query_multisig_members(), members);
@@ -59,6 +60,7 @@ fn initialize_multisig_with_custom_quorum() {
         members: members.clone(),
         quorum_bps: 5_000u32,
         version_proposal: 0u32,
+        upgrade_history: vec![&env],
     };
 
     assert_eq!(multisig.query_multisig_info(), expected_response);
@@ -86,6 +88,8 @@ fn double_initialize_is_forbidden() {
             &String::from_str(&env, "Example description of this multisig"),
             &members.clone(),
             &None,
+            &None,
+            &None,
         ),
         Err(Ok(ContractError::AlreadyInitialized)),
     );
@@ -106,6 +110,8 @@ fn initialize_name_too_long() {
             &String::from_str(&env, "Example description of this multisig"),
             &members.clone(),
             &None,
+            &None,
+            &None,
         ),
         Err(Ok(ContractError::TitleTooLong))
     );
@@ -126,6 +132,8 @@ fn initialize_description_too_long() {
             &String::from_bytes(&env, &[0u8; 1025]),
             &members.clone(),
             &None,
+            &None,
+            &None,
         ),
         Err(Ok(ContractError::DescriptionTooLong))
     );
@@ -146,6 +154,8 @@ fn initialize_bps_too_small() {
             &String::from_str(&env, "Description"),
             &members.clone(),
             &Some(100u32),
+            &None,
+            &None,
         ),
         Err(Ok(ContractError::InitializeTooLowQuorum))
     );
@@ -166,6 +176,8 @@ fn initialize_bps_too_big() {
             &String::from_str(&env, "Description"),
             &members.clone(),
             &Some(10_001u32),
+            &None,
+            &None,
         ),
         Err(Ok(ContractError::InitializeTooHighQuorum))
     );
@@ -0,0 +1,245 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+use super::{
+    setup::{initialize_multisig_contract, sign_and_execute_proposal, upgrade, DAY_AS_TIMESTAMP},
+    update_proposal::utils,
+};
+use crate::{
+    contract::{Multisig, MultisigClient},
+    error::ContractError,
+    storage::{ConfigUpdate, ProposalType, UpgradeRecord},
+};
+
+#[test]
+fn rollback_restores_previous_wasm_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone(), member3.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    multisig.create_config_proposal(
+        &member1,
+        &String::from_str(&env, "Rollback quorum"),
+        &String::from_str(&env, "Half of the members can revert a release"),
+        &ConfigUpdate::RollbackQuorumBps(5_000),
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+
+    // the code the multisig was deployed with is unknown
+    let rollback = |multisig: &MultisigClient| {
+        multisig.try_create_rollback_proposal(
+            &member1,
+            &String::from_str(&env, "Rollback"),
+            &String::from_str(&env, "Revert the latest release"),
+            &None,
+        )
+    };
+    assert_eq!(
+        rollback(&multisig),
+        Err(Ok(ContractError::NoPreviousVersion))
+    );
+
+    let release1 = utils::multisig_release_wasm_hash(&env, 1);
+    let release2 = utils::multisig_release_wasm_hash(&env, 2);
+
+    env.ledger().with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP);
+    upgrade(&multisig, &members, &release1);
+    assert_eq!(
+        rollback(&multisig),
+        Err(Ok(ContractError::NoPreviousVersion))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP * 2);
+    upgrade(&multisig, &members, &release2);
+
+    rollback(&multisig).unwrap().unwrap();
+    let proposal_id = multisig.query_last_proposal_id();
+    assert_eq!(multisig.query_proposal(&proposal_id).stages, vec![&env]);
+
+    // the rollback quorum applies instead of the regular 100% quorum
+    multisig.sign_proposal(&member1, &proposal_id);
+    assert!(!multisig.is_proposal_ready(&proposal_id));
+    multisig.sign_proposal(&member2, &proposal_id);
+    assert!(multisig.is_proposal_ready(&proposal_id));
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP * 3);
+    multisig.execute_proposal(&member1, &proposal_id);
+
+    let info = multisig.query_multisig_info();
+    assert_eq!(info.version_proposal, 3);
+    assert_eq!(
+        info.upgrade_history,
+        vec![
+            &env,
+            UpgradeRecord {
+                wasm_hash: release1.clone(),
+                timestamp: DAY_AS_TIMESTAMP,
+                proposal_id: 2,
+                with_migration: false,
+            },
+            UpgradeRecord {
+                wasm_hash: release2.clone(),
+                timestamp: DAY_AS_TIMESTAMP * 2,
+                proposal_id: 3,
+                with_migration: false,
+            },
+            UpgradeRecord {
+                wasm_hash: release1.clone(),
+                timestamp: DAY_AS_TIMESTAMP * 3,
+                proposal_id,
+                with_migration: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn outdated_or_migrated_rollback_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    upgrade(
+        &multisig,
+        &members,
+        &utils::multisig_release_wasm_hash(&env, 1),
+    );
+    upgrade(
+        &multisig,
+        &members,
+        &utils::multisig_release_wasm_hash(&env, 2),
+    );
+
+    multisig.create_rollback_proposal(
+        &member1,
+        &String::from_str(&env, "Rollback"),
+        &String::from_str(&env, "Revert the latest release"),
+        &None,
+    );
+    let rollback_id = multisig.query_last_proposal_id();
+    multisig.sign_proposal(&member1, &rollback_id);
+
+    // a newer release was installed after the rollback was proposed
+    upgrade(
+        &multisig,
+        &members,
+        &utils::multisig_release_wasm_hash(&env, 3),
+    );
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &rollback_id),
+        Err(Ok(ContractError::NoPreviousVersion))
+    );
+
+    // the previous code can't read migrated storage
+    multisig.create_migration_proposal(
+        &member1,
+        &String::from_str(&env, "Upgrade"),
+        &String::from_str(&env, "New release with migration"),
+        &utils::multisig_release_wasm_hash(&env, 4),
+        &multisig.query_storage_version(),
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(
+        multisig.try_create_rollback_proposal(
+            &member1,
+            &String::from_str(&env, "Rollback"),
+            &String::from_str(&env, "Revert the latest release"),
+            &None,
+        ),
+        Err(Ok(ContractError::MigratedUpgradeNotRevertible))
+    );
+}
+
+#[test]
+fn rollback_restores_deployed_wasm_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let deployed = utils::multisig_release_wasm_hash(&env, 0);
+    let multisig = MultisigClient::new(&env, &env.register_contract(None, Multisig {}));
+    multisig.initialize(
+        &String::from_str(&env, "MultisigName"),
+        &String::from_str(&env, "Example description of this multisig"),
+        &members,
+        &None,
+        &None,
+        &Some(deployed.clone()),
+    );
+
+    let release1 = utils::multisig_release_wasm_hash(&env, 1);
+    env.ledger().with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP);
+    upgrade(&multisig, &members, &release1);
+
+    // the first upgrade can be rolled back to the deployed code
+    multisig.create_rollback_proposal(
+        &member1,
+        &String::from_str(&env, "Rollback"),
+        &String::from_str(&env, "Revert the first release"),
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+    assert_eq!(
+        multisig.query_proposal(&proposal_id).proposal,
+        ProposalType::Rollback(deployed.clone())
+    );
+    sign_and_execute_proposal(&multisig, &members, proposal_id);
+
+    assert_eq!(
+        multisig.query_multisig_info().upgrade_history,
+        vec![
+            &env,
+            UpgradeRecord {
+                wasm_hash: deployed.clone(),
+                timestamp: 0,
+                proposal_id: 0,
+                with_migration: false,
+            },
+            UpgradeRecord {
+                wasm_hash: release1,
+                timestamp: DAY_AS_TIMESTAMP,
+                proposal_id: 1,
+                with_migration: false,
+            },
+            UpgradeRecord {
+                wasm_hash: deployed,
+                timestamp: DAY_AS_TIMESTAMP,
+                proposal_id,
+                with_migration: false,
+            },
+        ]
+    );
+}
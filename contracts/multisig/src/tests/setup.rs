@@ -1,7 +1,8 @@
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Vec};
 
 use crate::{
     contract::{Multisig, MultisigClient},
+    storage::{ConfigUpdate, StreamAction, VestingAction},
     token_contract, SEVEN_DAYS_EXPIRATION_DATE,
 };
 
@@ -16,6 +17,37 @@ pub fn deploy_token_contract<'a>(env: &Env, admin: &Address) -> token_contract::
     )
 }
 
+// token with `amount` already held by the multisig, so transaction proposals can reserve it
+pub fn deploy_funded_token_contract<'a>(
+    env: &Env,
+    admin: &Address,
+    multisig: &MultisigClient,
+    amount: i128,
+) -> token_contract::Client<'a> {
+    let token = deploy_token_contract(env, admin);
+    token.mint(&multisig.address, &amount);
+
+    token
+}
+
+// token built from `contracts/token`, for amounts with a custom number of decimals
+pub fn deploy_token_contract_with_decimals<'a>(
+    env: &Env,
+    admin: &Address,
+    decimals: u32,
+) -> token_contract::Client<'a> {
+    let token =
+        token_contract::Client::new(env, &env.register_contract_wasm(None, token_contract::WASM));
+    token.initialize(
+        admin,
+        &decimals,
+        &String::from_str(env, "Token"),
+        &String::from_str(env, "TKN"),
+    );
+
+    token
+}
+
 pub fn initialize_multisig_contract<'a>(
     env: &Env,
     name: String,
@@ -25,7 +57,96 @@ pub fn initialize_multisig_contract<'a>(
 ) -> MultisigClient<'a> {
     let multisig = MultisigClient::new(env, &env.register_contract(None, Multisig {}));
 
-    multisig.initialize(&name, &description, &members, &quorum_bps.into());
+    multisig.initialize(
+        &name,
+        &description,
+        &members,
+        &quorum_bps.into(),
+        &None,
+        &None,
+    );
 
     multisig
 }
+
+// signs the proposal with every member and executes it
+pub fn sign_and_execute_proposal(
+    multisig: &MultisigClient,
+    members: &Vec<Address>,
+    proposal_id: u64,
+) {
+    for member in members.iter() {
+        multisig.sign_proposal(&member, &proposal_id);
+    }
+    multisig.execute_proposal(&members.first().unwrap(), &proposal_id);
+}
+
+// creates a transaction proposal paying a new address and returns its ID
+pub fn create_transaction(
+    env: &Env,
+    multisig: &MultisigClient,
+    sender: &Address,
+    token: &token_contract::Client,
+) -> u64 {
+    multisig.create_transaction_proposal(
+        sender,
+        &String::from_str(env, "Transaction"),
+        &String::from_str(env, "Payment of an invoice"),
+        &Address::generate(env),
+        &1_000,
+        &token.address,
+        &None,
+    );
+    multisig.query_last_proposal_id()
+}
+
+// The helpers below create a proposal with the first member, sign it with every member
+// and execute it
+
+pub fn update_config(multisig: &MultisigClient, members: &Vec<Address>, config: ConfigUpdate) {
+    let env = &multisig.env;
+    multisig.create_config_proposal(
+        &members.first().unwrap(),
+        &String::from_str(env, "Config"),
+        &String::from_str(env, "Update the configuration"),
+        &config,
+        &None,
+    );
+    sign_and_execute_proposal(multisig, members, multisig.query_last_proposal_id());
+}
+
+pub fn upgrade(multisig: &MultisigClient, members: &Vec<Address>, wasm_hash: &BytesN<32>) {
+    let env = &multisig.env;
+    multisig.create_update_proposal(
+        &members.first().unwrap(),
+        &String::from_str(env, "Upgrade"),
+        &String::from_str(env, "New release"),
+        wasm_hash,
+        &None,
+    );
+    sign_and_execute_proposal(multisig, members, multisig.query_last_proposal_id());
+}
+
+pub fn update_stream(multisig: &MultisigClient, members: &Vec<Address>, action: StreamAction) {
+    let env = &multisig.env;
+    multisig.create_stream_proposal(
+        &members.first().unwrap(),
+        &String::from_str(env, "Payroll"),
+        &String::from_str(env, "Weekly salary of the developer"),
+        &action,
+        &None,
+    );
+    sign_and_execute_proposal(multisig, members, multisig.query_last_proposal_id());
+}
+
+pub fn update_vesting(multisig: &MultisigClient, members: &Vec<Address>, action: VestingAction) {
+    let env = &multisig.env;
+    multisig.create_vesting_proposal(
+        &members.first().unwrap(),
+        &String::from_str(env, "Vesting"),
+        &String::from_str(env, "Team allocation of the new contributor"),
+        &action,
+        &None,
+    );
+    sign_and_execute_proposal(multisig, members, multisig.query_last_proposal_id());
+}
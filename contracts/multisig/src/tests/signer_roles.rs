@@ -0,0 +1,210 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+use super::setup::{
+    deploy_funded_token_contract, deploy_token_contract, initialize_multisig_contract,
+    update_config,
+};
+use crate::{
+    error::ContractError,
+    storage::{ConfigUpdate, ProposalStatus, SignerRoles},
+};
+
+#[test]
+fn mandatory_signer_is_required_for_execution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let cfo = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone(), cfo.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        5_000u32,
+    );
+
+    let signer_roles = SignerRoles {
+        mandatory_signers: vec![&env, cfo.clone()],
+        veto_holders: vec![&env],
+    };
+    update_config(
+        &multisig,
+        &members,
+        ConfigUpdate::SignerRoles(signer_roles.clone()),
+    );
+    assert_eq!(multisig.query_signer_roles(), signer_roles);
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+    let recipient = Address::generate(&env);
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &recipient,
+        &10_000,
+        &token.address,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+
+    // quorum ratio is reached, but CFO hasn't signed yet
+    multisig.sign_proposal(&member1, &proposal_id);
+    multisig.sign_proposal(&member2, &proposal_id);
+    assert!(!multisig.is_proposal_ready(&proposal_id));
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::MandatorySignatureMissing))
+    );
+
+    multisig.sign_proposal(&cfo, &proposal_id);
+    assert!(multisig.is_proposal_ready(&proposal_id));
+    multisig.execute_proposal(&member1, &proposal_id);
+    assert_eq!(token.balance(&recipient), 10_000i128);
+}
+
+#[test]
+fn mandatory_signer_alone_does_not_reach_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let cfo = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone(), cfo.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        5_000u32,
+    );
+
+    update_config(
+        &multisig,
+        &members,
+        ConfigUpdate::SignerRoles(SignerRoles {
+            mandatory_signers: vec![&env, cfo.clone()],
+            veto_holders: vec![&env],
+        }),
+    );
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &Address::generate(&env),
+        &10_000,
+        &deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+
+    multisig.sign_proposal(&cfo, &proposal_id);
+    assert_eq!(
+        multisig.try_execute_proposal(&cfo, &proposal_id),
+        Err(Ok(ContractError::QuorumNotReached))
+    );
+}
+
+#[test]
+fn veto_holder_rejects_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let vetoer = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone(), vetoer.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        5_000u32,
+    );
+
+    update_config(
+        &multisig,
+        &members,
+        ConfigUpdate::SignerRoles(SignerRoles {
+            mandatory_signers: vec![&env],
+            veto_holders: vec![&env, vetoer.clone()],
+        }),
+    );
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &Address::generate(&env),
+        &10_000,
+        &deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+    multisig.sign_proposal(&member1, &proposal_id);
+    multisig.sign_proposal(&member2, &proposal_id);
+
+    // only veto holders can reject
+    assert_eq!(
+        multisig.try_reject_proposal(&member2, &proposal_id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    multisig.reject_proposal(&vetoer, &proposal_id);
+    assert_eq!(
+        multisig.query_proposal(&proposal_id).status,
+        ProposalStatus::Rejected
+    );
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::ProposalClosed))
+    );
+    assert_eq!(
+        multisig.try_sign_proposal(&vetoer, &proposal_id),
+        Err(Ok(ContractError::ProposalClosed))
+    );
+    assert_eq!(
+        multisig.try_reject_proposal(&vetoer, &proposal_id),
+        Err(Ok(ContractError::ProposalClosed))
+    );
+}
+
+#[test]
+fn signer_roles_can_only_be_given_to_members() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    assert_eq!(
+        multisig.try_create_config_proposal(
+            &member1,
+            &String::from_str(&env, "Signer roles"),
+            &String::from_str(&env, "Description"),
+            &ConfigUpdate::SignerRoles(SignerRoles {
+                mandatory_signers: vec![&env],
+                veto_holders: vec![&env, Address::generate(&env)],
+            }),
+            &None,
+        ),
+        Err(Ok(ContractError::UnauthorizedNotAMember))
+    );
+}
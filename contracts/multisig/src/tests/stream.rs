@@ -0,0 +1,265 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+use super::setup::{
+    deploy_token_contract, initialize_multisig_contract, update_stream, DAY_AS_TIMESTAMP,
+};
+use crate::{
+    error::ContractError,
+    storage::{Stream, StreamAction, StreamTerms, StreamUpdate},
+};
+
+const WEEK_AS_TIMESTAMP: u64 = DAY_AS_TIMESTAMP * 7;
+
+#[test]
+fn recipient_claims_accrued_stream() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let recipient = Address::generate(&env);
+    update_stream(
+        &multisig,
+        &members,
+        StreamAction::Create(StreamTerms {
+            recipient: recipient.clone(),
+            token: token.address.clone(),
+            amount_per_period: 1_000,
+            period: WEEK_AS_TIMESTAMP,
+            start_timestamp: 0,
+            end_timestamp: WEEK_AS_TIMESTAMP * 4,
+        }),
+    );
+    assert_eq!(multisig.query_last_stream_id(), 1);
+    assert_eq!(
+        multisig.query_stream(&1),
+        Stream {
+            id: 1,
+            recipient: recipient.clone(),
+            token: token.address.clone(),
+            amount_per_period: 1_000,
+            period: WEEK_AS_TIMESTAMP,
+            start_timestamp: 0,
+            end_timestamp: WEEK_AS_TIMESTAMP * 4,
+            carried_over: 0,
+            claimed: 0,
+        }
+    );
+
+    // the remaining stream is reserved
+    assert_eq!(
+        multisig.try_create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &Address::generate(&env),
+            &6_001,
+            &token.address,
+            &None,
+        ),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+
+    // nothing accrued before the first full period
+    env.ledger()
+        .with_mut(|li| li.timestamp = WEEK_AS_TIMESTAMP - 1);
+    assert_eq!(
+        multisig.try_claim_stream(&recipient, &1),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = WEEK_AS_TIMESTAMP * 2 + DAY_AS_TIMESTAMP);
+    assert_eq!(multisig.claim_stream(&recipient, &1), 2_000);
+    assert_eq!(token.balance(&recipient), 2_000);
+
+    // only the recipient can claim
+    env.ledger()
+        .with_mut(|li| li.timestamp = WEEK_AS_TIMESTAMP * 3);
+    assert_eq!(
+        multisig.try_claim_stream(&member1, &1),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    // nothing accrues after the end
+    env.ledger()
+        .with_mut(|li| li.timestamp = WEEK_AS_TIMESTAMP * 10);
+    assert_eq!(multisig.claim_stream(&recipient, &1), 2_000);
+    assert_eq!(token.balance(&recipient), 4_000);
+    assert_eq!(multisig.query_stream(&1).claimed, 4_000);
+    assert_eq!(
+        multisig.try_claim_stream(&recipient, &1),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    assert_eq!(
+        multisig.try_claim_stream(&recipient, &2),
+        Err(Ok(ContractError::StreamNotFound))
+    );
+}
+
+#[test]
+fn stream_can_be_updated_and_cancelled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &100_000);
+
+    let recipient = Address::generate(&env);
+    update_stream(
+        &multisig,
+        &members,
+        StreamAction::Create(StreamTerms {
+            recipient: recipient.clone(),
+            token: token.address.clone(),
+            amount_per_period: 1_000,
+            period: WEEK_AS_TIMESTAMP,
+            start_timestamp: 0,
+            end_timestamp: WEEK_AS_TIMESTAMP * 10,
+        }),
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = WEEK_AS_TIMESTAMP + DAY_AS_TIMESTAMP);
+    multisig.claim_stream(&recipient, &1);
+
+    // a raise after the third week, the unclaimed second week is carried over
+    env.ledger()
+        .with_mut(|li| li.timestamp = WEEK_AS_TIMESTAMP * 2 + DAY_AS_TIMESTAMP);
+    update_stream(
+        &multisig,
+        &members,
+        StreamAction::Update(StreamUpdate {
+            stream_id: 1,
+            amount_per_period: 1_500,
+            period: WEEK_AS_TIMESTAMP,
+            end_timestamp: WEEK_AS_TIMESTAMP * 10,
+        }),
+    );
+    let stream = multisig.query_stream(&1);
+    assert_eq!(stream.start_timestamp, WEEK_AS_TIMESTAMP * 2);
+    assert_eq!(stream.carried_over, 1_000);
+    assert_eq!(stream.claimed, 0);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = WEEK_AS_TIMESTAMP * 4);
+    assert_eq!(multisig.claim_stream(&recipient, &1), 4_000);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = WEEK_AS_TIMESTAMP * 5 + DAY_AS_TIMESTAMP);
+    update_stream(&multisig, &members, StreamAction::Cancel(1));
+    let stream = multisig.query_stream(&1);
+    assert_eq!(stream.end_timestamp, WEEK_AS_TIMESTAMP * 5);
+    assert_eq!(stream.carried_over, 1_500);
+
+    // what accrued before the cancellation can still be claimed
+    env.ledger()
+        .with_mut(|li| li.timestamp = WEEK_AS_TIMESTAMP * 8);
+    assert_eq!(multisig.claim_stream(&recipient, &1), 1_500);
+    assert_eq!(token.balance(&recipient), 6_500);
+    assert_eq!(
+        multisig.try_claim_stream(&recipient, &1),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+}
+
+#[test]
+fn invalid_stream_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        vec![&env, member1.clone()],
+        None,
+    );
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let create_stream = |action: StreamAction| {
+        multisig.try_create_stream_proposal(
+            &member1,
+            &String::from_str(&env, "Payroll"),
+            &String::from_str(&env, "Weekly salary of the developer"),
+            &action,
+            &None,
+        )
+    };
+    let terms = StreamTerms {
+        recipient: Address::generate(&env),
+        token: token.address.clone(),
+        amount_per_period: 1_000,
+        period: WEEK_AS_TIMESTAMP,
+        start_timestamp: 0,
+        end_timestamp: WEEK_AS_TIMESTAMP * 4,
+    };
+
+    for terms in [
+        StreamTerms {
+            amount_per_period: 0,
+            ..terms.clone()
+        },
+        StreamTerms {
+            period: 0,
+            ..terms.clone()
+        },
+        StreamTerms {
+            end_timestamp: 0,
+            ..terms.clone()
+        },
+    ] {
+        assert_eq!(
+            create_stream(StreamAction::Create(terms)),
+            Err(Ok(ContractError::InvalidStream))
+        );
+    }
+    assert_eq!(
+        create_stream(StreamAction::Create(StreamTerms {
+            end_timestamp: WEEK_AS_TIMESTAMP * 11,
+            ..terms.clone()
+        })),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    assert_eq!(
+        create_stream(StreamAction::Cancel(1)),
+        Err(Ok(ContractError::StreamNotFound))
+    );
+    assert_eq!(
+        create_stream(StreamAction::Update(StreamUpdate {
+            stream_id: 1,
+            amount_per_period: 1_000,
+            period: WEEK_AS_TIMESTAMP,
+            end_timestamp: WEEK_AS_TIMESTAMP * 4,
+        })),
+        Err(Ok(ContractError::StreamNotFound))
+    );
+}
@@ -0,0 +1,149 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+use super::setup::{
+    deploy_token_contract, initialize_multisig_contract, sign_and_execute_proposal,
+    DAY_AS_TIMESTAMP,
+};
+use crate::{error::ContractError, storage::Swap};
+
+#[test]
+fn swap_exchanges_both_sides_atomically() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token_a = deploy_token_contract(&env, &member1);
+    let token_b = deploy_token_contract(&env, &member1);
+    let market_maker = Address::generate(&env);
+    token_a.mint(&multisig.address, &10_000);
+    token_b.mint(&market_maker, &50_000);
+
+    let swap = Swap {
+        counterparty: market_maker.clone(),
+        send_token: token_a.address.clone(),
+        send_amount: 10_000,
+        receive_token: token_b.address.clone(),
+        receive_amount: 30_000,
+    };
+    multisig.create_swap_proposal(
+        &member1,
+        &String::from_str(&env, "OTC"),
+        &String::from_str(&env, "Sell A for B"),
+        &swap,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+    assert_eq!(token_b.balance(&market_maker), 20_000);
+    assert_eq!(token_b.balance(&multisig.address), 30_000);
+
+    // the escrow isn't available to the treasury yet
+    let treasury = multisig.query_treasury(&vec![&env, token_b.address.clone()]);
+    assert_eq!(treasury.get(0).unwrap().available, 0);
+    assert_eq!(
+        multisig.try_refund_swap(&proposal_id),
+        Err(Ok(ContractError::SwapNotRefundable))
+    );
+
+    sign_and_execute_proposal(&multisig, &members, proposal_id);
+    assert_eq!(token_a.balance(&market_maker), 10_000);
+    assert_eq!(token_a.balance(&multisig.address), 0);
+    assert_eq!(token_b.balance(&multisig.address), 30_000);
+    let treasury = multisig.query_treasury(&vec![&env, token_b.address.clone()]);
+    assert_eq!(treasury.get(0).unwrap().available, 30_000);
+    assert_eq!(
+        multisig.try_refund_swap(&proposal_id),
+        Err(Ok(ContractError::SwapNotRefundable))
+    );
+}
+
+#[test]
+fn expired_swap_is_refunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token_a = deploy_token_contract(&env, &member1);
+    let token_b = deploy_token_contract(&env, &member1);
+    let market_maker = Address::generate(&env);
+    token_a.mint(&multisig.address, &10_000);
+    token_b.mint(&market_maker, &30_000);
+
+    let create_swap = |swap: &Swap| {
+        multisig.try_create_swap_proposal(
+            &member1,
+            &String::from_str(&env, "OTC"),
+            &String::from_str(&env, "Sell A for B"),
+            swap,
+            &Some(DAY_AS_TIMESTAMP),
+        )
+    };
+    let swap = Swap {
+        counterparty: market_maker.clone(),
+        send_token: token_a.address.clone(),
+        send_amount: 10_000,
+        receive_token: token_b.address.clone(),
+        receive_amount: 30_000,
+    };
+    assert_eq!(
+        create_swap(&Swap {
+            send_amount: 10_001,
+            ..swap.clone()
+        }),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    assert_eq!(
+        create_swap(&Swap {
+            receive_token: token_a.address.clone(),
+            ..swap.clone()
+        }),
+        Err(Ok(ContractError::InvalidSwap))
+    );
+    assert_eq!(
+        create_swap(&Swap {
+            receive_amount: 0,
+            ..swap.clone()
+        }),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+
+    create_swap(&swap).unwrap().unwrap();
+    let proposal_id = multisig.query_last_proposal_id();
+    multisig.sign_proposal(&member1, &proposal_id);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP + 1);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::ProposalExpired))
+    );
+    multisig.refund_swap(&proposal_id);
+    assert_eq!(token_b.balance(&market_maker), 30_000);
+    assert_eq!(token_a.balance(&multisig.address), 10_000);
+    assert_eq!(
+        multisig.try_refund_swap(&proposal_id),
+        Err(Ok(ContractError::SwapNotRefundable))
+    );
+}
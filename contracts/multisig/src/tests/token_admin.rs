@@ -0,0 +1,226 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+use super::setup::{
+    deploy_token_contract, initialize_multisig_contract, sign_and_execute_proposal,
+};
+use crate::{
+    error::ContractError,
+    storage::{ConfigUpdate, TreasuryBalance},
+};
+
+#[test]
+fn buyback_collects_and_burns_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    let market_maker = Address::generate(&env);
+    token.mint(&market_maker, &10_000);
+    token.approve(&market_maker, &multisig.address, &6_000, &1_000);
+    multisig.create_config_proposal(
+        &member1,
+        &String::from_str(&env, "Treasury"),
+        &String::from_str(&env, "Track the token"),
+        &ConfigUpdate::TrackToken(token.address.clone()),
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+
+    multisig.create_transfer_from_proposal(
+        &member1,
+        &String::from_str(&env, "Buyback"),
+        &String::from_str(&env, "Collect the repurchased tokens"),
+        &token.address,
+        &market_maker,
+        &multisig.address,
+        &6_000,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(token.balance(&market_maker), 4_000);
+    assert_eq!(token.balance(&multisig.address), 6_000);
+
+    assert_eq!(
+        multisig.try_create_burn_proposal(
+            &member1,
+            &String::from_str(&env, "Burn"),
+            &String::from_str(&env, "Burn the repurchased tokens"),
+            &token.address,
+            &6_001,
+            &None,
+        ),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    multisig.create_burn_proposal(
+        &member1,
+        &String::from_str(&env, "Burn"),
+        &String::from_str(&env, "Burn the repurchased tokens"),
+        &token.address,
+        &5_000,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+
+    assert_eq!(
+        multisig.query_treasury(&vec![&env]),
+        vec![
+            &env,
+            TreasuryBalance {
+                token: token.address.clone(),
+                balance: 1_000,
+                reserved: 0,
+                available: 1_000,
+                total_inflow: 6_000,
+                total_outflow: 5_000,
+            }
+        ]
+    );
+}
+
+#[test]
+fn transfer_from_to_another_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    let payer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    token.mint(&payer, &10_000);
+    token.approve(&payer, &multisig.address, &3_000, &1_000);
+
+    assert_eq!(
+        multisig.try_create_transfer_from_proposal(
+            &member1,
+            &String::from_str(&env, "Collect"),
+            &String::from_str(&env, "Invoice #7"),
+            &token.address,
+            &payer,
+            &recipient,
+            &0,
+            &None,
+        ),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    multisig.create_transfer_from_proposal(
+        &member1,
+        &String::from_str(&env, "Collect"),
+        &String::from_str(&env, "Invoice #7"),
+        &token.address,
+        &payer,
+        &recipient,
+        &3_000,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(token.balance(&recipient), 3_000);
+    assert_eq!(token.allowance(&payer, &multisig.address), 0);
+    assert_eq!(multisig.query_tracked_tokens(), vec![&env]);
+}
+
+#[test]
+fn mint_and_set_admin_of_administered_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &multisig.address);
+    let recipient = Address::generate(&env);
+    multisig.create_config_proposal(
+        &member1,
+        &String::from_str(&env, "Treasury"),
+        &String::from_str(&env, "Track the token"),
+        &ConfigUpdate::TrackToken(token.address.clone()),
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+
+    assert_eq!(
+        multisig.try_create_mint_proposal(
+            &member1,
+            &String::from_str(&env, "Mint"),
+            &String::from_str(&env, "Community rewards"),
+            &token.address,
+            &recipient,
+            &-1,
+            &None,
+        ),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    multisig.create_mint_proposal(
+        &member1,
+        &String::from_str(&env, "Mint"),
+        &String::from_str(&env, "Community rewards"),
+        &token.address,
+        &recipient,
+        &7_000,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(token.balance(&recipient), 7_000);
+
+    multisig.create_mint_proposal(
+        &member1,
+        &String::from_str(&env, "Mint"),
+        &String::from_str(&env, "Treasury allocation"),
+        &token.address,
+        &multisig.address,
+        &3_000,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(
+        multisig
+            .query_treasury(&vec![&env])
+            .get(0)
+            .unwrap()
+            .total_inflow,
+        3_000
+    );
+
+    let new_admin = Address::generate(&env);
+    multisig.create_set_token_admin_proposal(
+        &member1,
+        &String::from_str(&env, "Set admin"),
+        &String::from_str(&env, "Hand over to the new multisig"),
+        &token.address,
+        &new_admin,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+
+    // minting now requires the authorization of the new admin
+    token.mint(&new_admin, &1);
+    assert_eq!(env.auths()[0].0, new_admin);
+}
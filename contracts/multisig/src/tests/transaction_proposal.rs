@@ -1,18 +1,19 @@
 extern crate std;
 
 use soroban_sdk::{
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger},
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger},
     vec, Address, Env, IntoVal, String, Symbol,
 };
 
 use super::setup::{
-    deploy_token_contract, initialize_multisig_contract, DAY_AS_TIMESTAMP,
+    deploy_funded_token_contract, deploy_token_contract, deploy_token_contract_with_decimals,
+    initialize_multisig_contract, sign_and_execute_proposal, DAY_AS_TIMESTAMP,
     TWO_WEEKS_EXPIRATION_DATE,
 };
 use crate::{
     error::ContractError,
-    storage::{Proposal, ProposalStatus, ProposalType, Transaction},
-    SEVEN_DAYS_EXPIRATION_DATE,
+    storage::{ConfigUpdate, Proposal, ProposalStatus, ProposalType, SignerRoles, Transaction},
+    ONE_HOUR, SEVEN_DAYS_EXPIRATION_DATE,
 };
 
 #[test]
@@ -68,7 +69,7 @@ fn propose_transaction_proposal_full_quorum() {
                         "TxTitle#01",
                         "TxTestDescription",
                         &recipient,
-                        10_000u64,
+                        10_000i128,
                         &token.address,
                         None::<u64>,
                     )
@@ -95,7 +96,10 @@ fn propose_transaction_proposal_full_quorum() {
             }),
             status: ProposalStatus::Open,
             creation_timestamp: 0,
-            expiration_timestamp: SEVEN_DAYS_EXPIRATION_DATE
+            expiration_timestamp: SEVEN_DAYS_EXPIRATION_DATE,
+            stages: vec![&env],
+            current_stage: 0,
+            not_before: None,
         }
     );
 
@@ -104,9 +108,9 @@ fn propose_transaction_proposal_full_quorum() {
         multisig.query_signatures(&1),
         vec![
             &env,
-            (member1.clone(), false),
-            (member2.clone(), false),
-            (member3.clone(), false)
+            (member1.clone(), false, None),
+            (member2.clone(), false, None),
+            (member3.clone(), false, None)
         ]
     );
 
@@ -115,9 +119,9 @@ fn propose_transaction_proposal_full_quorum() {
         multisig.query_signatures(&1),
         vec![
             &env,
-            (member1.clone(), true),
-            (member2.clone(), false),
-            (member3.clone(), false)
+            (member1.clone(), true, None),
+            (member2.clone(), false, None),
+            (member3.clone(), false, None)
         ]
     );
 
@@ -126,9 +130,9 @@ fn propose_transaction_proposal_full_quorum() {
         multisig.query_signatures(&1),
         vec![
             &env,
-            (member1.clone(), true),
-            (member2.clone(), false),
-            (member3.clone(), true)
+            (member1.clone(), true, None),
+            (member2.clone(), false, None),
+            (member3.clone(), true, None)
         ]
     );
 
@@ -137,9 +141,9 @@ fn propose_transaction_proposal_full_quorum() {
         multisig.query_signatures(&1),
         vec![
             &env,
-            (member1.clone(), true),
-            (member2.clone(), true),
-            (member3.clone(), true)
+            (member1.clone(), true, None),
+            (member2.clone(), true, None),
+            (member3.clone(), true, None)
         ]
     );
 
@@ -178,7 +182,7 @@ fn proposal_name_too_long() {
             &String::from_str(&env, "TxTestDescription"),
             &Address::generate(&env),
             &10_000,
-            &deploy_token_contract(&env, &member1).address,
+            &deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address,
             &None,
         ),
         Err(Ok(ContractError::TitleTooLong))
@@ -208,7 +212,7 @@ fn proposal_description_too_long() {
             &String::from_bytes(&env, &[0u8; 258]),
             &Address::generate(&env),
             &10_000,
-            &deploy_token_contract(&env, &member1).address,
+            &deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address,
             &None,
         ),
         Err(Ok(ContractError::DescriptionTooLong))
@@ -271,7 +275,7 @@ fn query_all_proposals() {
     );
 
     let token = deploy_token_contract(&env, &member1);
-    token.mint(&multisig.address, &25_000);
+    token.mint(&multisig.address, &30_000);
 
     let recipient1 = Address::generate(&env);
     let recipient2 = Address::generate(&env);
@@ -306,7 +310,7 @@ fn query_all_proposals() {
                         "TxTitle#01",
                         "TxTestDescription",
                         &recipient1,
-                        10_000u64,
+                        10_000i128,
                         &token.address,
                         None::<u64>,
                     )
@@ -347,7 +351,7 @@ fn query_all_proposals() {
                         "TxTitle#02",
                         "TxTestDescription",
                         &recipient2,
-                        15_000u64,
+                        15_000i128,
                         &token.address,
                         None::<u64>,
                     )
@@ -388,7 +392,7 @@ fn query_all_proposals() {
                         "TxTitle#03",
                         "TxTestDescription",
                         &recipient1,
-                        5_000u64,
+                        5_000i128,
                         &token.address,
                         None::<u64>,
                     )
@@ -442,7 +446,7 @@ mod non_member {
                 &String::from_str(&env, "TxTestDescription"),
                 &recipient,
                 &10_000,
-                &deploy_token_contract(&env, &member1).address,
+                &deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address,
                 &None
             ),
             Err(Ok(ContractError::UnauthorizedNotAMember))
@@ -457,7 +461,6 @@ mod non_member {
         let member1 = Address::generate(&env);
         let random = Address::generate(&env);
         let members = vec![&env, member1.clone()];
-        let token_address = deploy_token_contract(&env, &member1).address;
 
         let multisig = initialize_multisig_contract(
             &env,
@@ -466,6 +469,7 @@ mod non_member {
             members.clone(),
             None,
         );
+        let token_address = deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address;
 
         let recipient = Address::generate(&env);
 
@@ -499,7 +503,7 @@ mod non_member {
                             "TxTitle#01",
                             "TxTestDescription",
                             &recipient,
-                            10_000u64,
+                            10_000i128,
                             &token_address,
                             None::<u64>,
                         )
@@ -541,7 +545,7 @@ mod non_member {
             &String::from_str(&env, "TxTestDescription"),
             &recipient,
             &10_000,
-            &deploy_token_contract(&env, &member1).address,
+            &deploy_funded_token_contract(&env, &member1, &multisig, 10_000).address,
             &None,
         );
 
@@ -707,7 +711,7 @@ mod quorum {
                             "TxTitle#01",
                             "TxTestDescription",
                             &recipient,
-                            10_000u64,
+                            10_000i128,
                             &token.address,
                             None::<u64>
                         )
@@ -746,9 +750,9 @@ mod quorum {
             multisig.query_signatures(&1),
             vec![
                 &env,
-                (member1.clone(), true),
-                (member2.clone(), false),
-                (member3.clone(), false)
+                (member1.clone(), true, None),
+                (member2.clone(), false, None),
+                (member3.clone(), false, None)
             ]
         );
 
@@ -834,7 +838,7 @@ mod quorum {
                             "TxTitle#01",
                             "TxTestDescription",
                             &recipient,
-                            10_000u64,
+                            10_000i128,
                             &token.address,
                             None::<u64>
                         )
@@ -873,9 +877,9 @@ mod quorum {
             multisig.query_signatures(&1),
             vec![
                 &env,
-                (member1.clone(), true),
-                (member2.clone(), false),
-                (member3.clone(), false)
+                (member1.clone(), true, None),
+                (member2.clone(), false, None),
+                (member3.clone(), false, None)
             ]
         );
 
@@ -961,7 +965,7 @@ mod quorum {
                             "TxTitle#01",
                             "TxTestDescription",
                             &recipient,
-                            10_000u64,
+                            10_000i128,
                             &token.address,
                             None::<u64>
                         )
@@ -999,9 +1003,9 @@ mod quorum {
             multisig.query_signatures(&1),
             vec![
                 &env,
-                (member1.clone(), true),
-                (member2.clone(), false),
-                (member3.clone(), false)
+                (member1.clone(), true, None),
+                (member2.clone(), false, None),
+                (member3.clone(), false, None)
             ]
         );
 
@@ -1083,7 +1087,7 @@ mod quorum {
                             "TxTitle#01",
                             "TxTestDescription",
                             &recipient,
-                            10_000u64,
+                            10_000i128,
                             &token.address,
                             None::<u64>
                         )
@@ -1306,16 +1310,16 @@ mod quorum {
             multisig.query_signatures(&1),
             vec![
                 &env,
-                (member1.clone(), true),
-                (member2.clone(), true),
-                (member3.clone(), true),
-                (member4.clone(), true),
-                (member5.clone(), true),
-                (member6.clone(), true),
-                (member7.clone(), true),
-                (member8.clone(), true),
-                (member9.clone(), false),
-                (member0.clone(), true),
+                (member1.clone(), true, None),
+                (member2.clone(), true, None),
+                (member3.clone(), true, None),
+                (member4.clone(), true, None),
+                (member5.clone(), true, None),
+                (member6.clone(), true, None),
+                (member7.clone(), true, None),
+                (member8.clone(), true, None),
+                (member9.clone(), false, None),
+                (member0.clone(), true, None),
             ]
         );
 
@@ -1392,16 +1396,16 @@ mod quorum {
             multisig.query_signatures(&1),
             vec![
                 &env,
-                (member1.clone(), true),
-                (member2.clone(), true),
-                (member3.clone(), true),
-                (member4.clone(), true),
-                (member5.clone(), true),
-                (member6.clone(), true),
-                (member7.clone(), true),
-                (member8.clone(), true),
-                (member9.clone(), false),
-                (member0.clone(), true),
+                (member1.clone(), true, None),
+                (member2.clone(), true, None),
+                (member3.clone(), true, None),
+                (member4.clone(), true, None),
+                (member5.clone(), true, None),
+                (member6.clone(), true, None),
+                (member7.clone(), true, None),
+                (member8.clone(), true, None),
+                (member9.clone(), false, None),
+                (member0.clone(), true, None),
             ]
         );
 
@@ -1488,7 +1492,10 @@ fn multiple_active_proposals() {
             }),
             status: ProposalStatus::Open,
             creation_timestamp: 0,
-            expiration_timestamp: SEVEN_DAYS_EXPIRATION_DATE
+            expiration_timestamp: SEVEN_DAYS_EXPIRATION_DATE,
+            stages: vec![&env],
+            current_stage: 0,
+            not_before: None,
         }
     );
     assert_eq!(
@@ -1505,7 +1512,10 @@ fn multiple_active_proposals() {
             }),
             status: ProposalStatus::Open,
             creation_timestamp: 0,
-            expiration_timestamp: SEVEN_DAYS_EXPIRATION_DATE
+            expiration_timestamp: SEVEN_DAYS_EXPIRATION_DATE,
+            stages: vec![&env],
+            current_stage: 0,
+            not_before: None,
         }
     );
     assert_eq!(
@@ -1522,7 +1532,10 @@ fn multiple_active_proposals() {
             }),
             status: ProposalStatus::Open,
             creation_timestamp: 0,
-            expiration_timestamp: SEVEN_DAYS_EXPIRATION_DATE
+            expiration_timestamp: SEVEN_DAYS_EXPIRATION_DATE,
+            stages: vec![&env],
+            current_stage: 0,
+            not_before: None,
         }
     );
 
@@ -1551,9 +1564,9 @@ fn multiple_active_proposals() {
         multisig.query_signatures(&1),
         vec![
             &env,
-            (member1.clone(), true),
-            (member2.clone(), true),
-            (member3.clone(), false)
+            (member1.clone(), true, None),
+            (member2.clone(), true, None),
+            (member3.clone(), false, None)
         ]
     );
 
@@ -1561,9 +1574,9 @@ fn multiple_active_proposals() {
         multisig.query_signatures(&2),
         vec![
             &env,
-            (member1.clone(), true),
-            (member2.clone(), false),
-            (member3.clone(), true)
+            (member1.clone(), true, None),
+            (member2.clone(), false, None),
+            (member3.clone(), true, None)
         ]
     );
 
@@ -1571,9 +1584,9 @@ fn multiple_active_proposals() {
         multisig.query_signatures(&3),
         vec![
             &env,
-            (member1.clone(), true),
-            (member2.clone(), true),
-            (member3.clone(), true)
+            (member1.clone(), true, None),
+            (member2.clone(), true, None),
+            (member3.clone(), true, None)
         ]
     );
 
@@ -1740,6 +1753,9 @@ fn create_and_execute_transaction_proposal_within_deadline() {
             status: ProposalStatus::Open,
             creation_timestamp: 0,
             expiration_timestamp: TWO_WEEKS_EXPIRATION_DATE - 4 * DAY_AS_TIMESTAMP,
+            stages: vec![&env],
+            current_stage: 0,
+            not_before: None,
         }
     );
 
@@ -1770,3 +1786,323 @@ fn create_and_execute_transaction_proposal_within_deadline() {
 
     assert_eq!(multisig.query_proposal(&1).status, ProposalStatus::Closed);
 }
+
+#[test]
+fn transaction_proposal_with_18_decimal_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let one_token = 10i128.pow(18);
+    let token = deploy_token_contract_with_decimals(&env, &member1, 18);
+    token.mint(&multisig.address, &(1_000_000 * one_token));
+
+    // 500k tokens are way past u64::MAX in the smallest unit
+    let amount = 500_000 * one_token;
+    assert!(amount > u64::MAX as i128);
+
+    let recipient = Address::generate(&env);
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &recipient,
+        &amount,
+        &token.address,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+    assert_eq!(
+        multisig.query_proposal(&proposal_id).proposal,
+        ProposalType::Transaction(Transaction {
+            token: token.address.clone(),
+            amount,
+            recipient: recipient.clone(),
+        })
+    );
+
+    sign_and_execute_proposal(&multisig, &members, proposal_id);
+    assert_eq!(token.balance(&recipient), amount);
+    assert_eq!(token.balance(&multisig.address), 500_000 * one_token);
+}
+
+#[test]
+fn create_transaction_proposal_should_fail_with_non_positive_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+    let token = deploy_token_contract(&env, &member1);
+
+    for amount in [0i128, -1, i128::MIN] {
+        assert_eq!(
+            multisig.try_create_transaction_proposal(
+                &member1,
+                &String::from_str(&env, "TxTitle#01"),
+                &String::from_str(&env, "TxTestDescription"),
+                &Address::generate(&env),
+                &amount,
+                &token.address,
+                &None,
+            ),
+            Err(Ok(ContractError::InvalidAmount))
+        );
+    }
+}
+
+#[test]
+fn transaction_proposal_with_not_before() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+    let token = deploy_funded_token_contract(&env, &member1, &multisig, 10_000);
+
+    let recipient = Address::generate(&env);
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "Payout"),
+        &String::from_str(&env, "Monthly payout"),
+        &recipient,
+        &10_000,
+        &token.address,
+        &Some(TWO_WEEKS_EXPIRATION_DATE),
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+
+    assert_eq!(
+        multisig.try_set_not_before(&member1, &proposal_id, &Some(TWO_WEEKS_EXPIRATION_DATE + 1)),
+        Err(Ok(ContractError::InvalidNotBefore))
+    );
+    multisig.set_not_before(&member1, &proposal_id, &Some(SEVEN_DAYS_EXPIRATION_DATE));
+    assert_eq!(
+        multisig.query_proposal(&proposal_id).not_before,
+        Some(SEVEN_DAYS_EXPIRATION_DATE)
+    );
+
+    // signatures are collected ahead of the payment date
+    multisig.sign_proposal(&member1, &proposal_id);
+    multisig.sign_proposal(&member2, &proposal_id);
+    assert_eq!(
+        multisig.try_set_not_before(&member1, &proposal_id, &None),
+        Err(Ok(ContractError::ProposalAlreadySigned))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = SEVEN_DAYS_EXPIRATION_DATE - 1);
+    assert!(!multisig.is_proposal_ready(&proposal_id));
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::ProposalNotReady))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = SEVEN_DAYS_EXPIRATION_DATE);
+    assert!(multisig.is_proposal_ready(&proposal_id));
+    multisig.execute_proposal(&member1, &proposal_id);
+    assert_eq!(token.balance(&recipient), 10_000);
+}
+
+#[test]
+fn amend_unsigned_transaction_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+    let token = deploy_funded_token_contract(&env, &member1, &multisig, 10_000);
+
+    let mistyped_recipient = Address::generate(&env);
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &mistyped_recipient,
+        &5_000,
+        &token.address,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+
+    let recipient = Address::generate(&env);
+    let amend = |sender: &Address, amount: Option<i128>, expiration_date: Option<u64>| {
+        multisig.try_amend_proposal(
+            sender,
+            &proposal_id,
+            &String::from_str(&env, "Invoice #12"),
+            &String::from_str(&env, "Corrected recipient"),
+            &Some(recipient.clone()),
+            &amount,
+            &expiration_date,
+        )
+    };
+    assert_eq!(
+        amend(&member2, None, None),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        amend(&member1, Some(10_001), None),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    assert_eq!(
+        amend(&member1, None, Some(ONE_HOUR - 1)),
+        Err(Ok(ContractError::InvalidExpirationDate))
+    );
+
+    amend(&member1, Some(8_000), Some(DAY_AS_TIMESTAMP))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                multisig.address.clone(),
+                (
+                    String::from_str(&env, "Multisig"),
+                    String::from_str(&env, "Amend proposal")
+                )
+                    .into_val(&env),
+                (proposal_id, 1u32).into_val(&env),
+            )
+        ]
+    );
+    amend(&member1, None, None).unwrap().unwrap();
+    assert_eq!(multisig.query_amendments(&proposal_id), 2);
+
+    let proposal = multisig.query_proposal(&proposal_id);
+    assert_eq!(proposal.title, String::from_str(&env, "Invoice #12"));
+    assert_eq!(proposal.expiration_timestamp, DAY_AS_TIMESTAMP);
+    assert_eq!(
+        proposal.proposal,
+        ProposalType::Transaction(Transaction {
+            token: token.address.clone(),
+            amount: 8_000,
+            recipient: recipient.clone(),
+        })
+    );
+
+    // signed proposals can't be amended anymore
+    multisig.sign_proposal(&member1, &proposal_id);
+    assert_eq!(
+        amend(&member1, None, None),
+        Err(Ok(ContractError::ProposalAlreadySigned))
+    );
+    multisig.sign_proposal(&member2, &proposal_id);
+    multisig.execute_proposal(&member1, &proposal_id);
+    assert_eq!(token.balance(&recipient), 8_000);
+    assert_eq!(token.balance(&mistyped_recipient), 0);
+
+    // only transactions have a recipient and an amount
+    multisig.create_config_proposal(
+        &member1,
+        &String::from_str(&env, "Roles"),
+        &String::from_str(&env, "Description"),
+        &ConfigUpdate::SignerRoles(SignerRoles {
+            mandatory_signers: vec![&env],
+            veto_holders: vec![&env],
+        }),
+        &None,
+    );
+    assert_eq!(
+        multisig.try_amend_proposal(
+            &member1,
+            &multisig.query_last_proposal_id(),
+            &String::from_str(&env, "Roles"),
+            &String::from_str(&env, "Description"),
+            &None,
+            &Some(1),
+            &None,
+        ),
+        Err(Ok(ContractError::InvalidAmendment))
+    );
+}
+
+#[test]
+fn expired_proposal_cannot_be_amended() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+    let token = deploy_funded_token_contract(&env, &member1, &multisig, 10_000);
+
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &Address::generate(&env),
+        &5_000,
+        &token.address,
+        &Some(DAY_AS_TIMESTAMP),
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+
+    // extending the expiration date doesn't revive the proposal
+    env.ledger()
+        .with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP + 1);
+    assert_eq!(
+        multisig.try_amend_proposal(
+            &member1,
+            &proposal_id,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &None,
+            &None,
+            &Some(SEVEN_DAYS_EXPIRATION_DATE),
+        ),
+        Err(Ok(ContractError::ProposalExpired))
+    );
+    assert_eq!(
+        multisig.query_proposal(&proposal_id).expiration_timestamp,
+        DAY_AS_TIMESTAMP
+    );
+}
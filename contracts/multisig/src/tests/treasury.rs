@@ -0,0 +1,340 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, String,
+};
+
+use super::setup::{
+    deploy_token_contract, initialize_multisig_contract, sign_and_execute_proposal, update_config,
+    DAY_AS_TIMESTAMP,
+};
+use crate::{
+    error::ContractError,
+    storage::{ConfigUpdate, Deposit, TreasuryBalance},
+};
+
+#[test]
+fn treasury_tracks_inflows_and_outflows() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let donor = Address::generate(&env);
+    let token1 = deploy_token_contract(&env, &member1);
+    let token2 = deploy_token_contract(&env, &member1);
+    token1.mint(&donor, &10_000);
+    token2.mint(&donor, &5_000);
+
+    update_config(
+        &multisig,
+        &vec![&env, member1.clone()],
+        ConfigUpdate::TrackToken(token1.address.clone()),
+    );
+    update_config(
+        &multisig,
+        &vec![&env, member1.clone()],
+        ConfigUpdate::TrackToken(token2.address.clone()),
+    );
+
+    multisig.deposit(
+        &donor,
+        &token1.address,
+        &7_000,
+        &String::from_str(&env, "Grant"),
+    );
+    multisig.deposit(
+        &donor,
+        &token1.address,
+        &3_000,
+        &String::from_str(&env, "Grant"),
+    );
+    multisig.deposit(
+        &donor,
+        &token2.address,
+        &5_000,
+        &String::from_str(&env, "Grant"),
+    );
+    // tokens sent directly aren't counted as inflow
+    token2.mint(&multisig.address, &1_000);
+
+    let recipient = Address::generate(&env);
+    multisig.create_transaction_proposal(
+        &member1,
+        &String::from_str(&env, "TxTitle#01"),
+        &String::from_str(&env, "TxTestDescription"),
+        &recipient,
+        &4_000,
+        &token1.address,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+
+    assert_eq!(
+        multisig.query_tracked_tokens(),
+        vec![&env, token1.address.clone(), token2.address.clone()]
+    );
+    assert_eq!(
+        multisig.query_treasury(&vec![&env]),
+        vec![
+            &env,
+            TreasuryBalance {
+                token: token1.address.clone(),
+                balance: 6_000,
+                reserved: 0,
+                available: 6_000,
+                total_inflow: 10_000,
+                total_outflow: 4_000,
+            },
+            TreasuryBalance {
+                token: token2.address.clone(),
+                balance: 6_000,
+                reserved: 0,
+                available: 6_000,
+                total_inflow: 5_000,
+                total_outflow: 0,
+            },
+        ]
+    );
+
+    // deposits of untracked tokens aren't counted and don't track the token
+    let token3 = deploy_token_contract(&env, &member1);
+    token3.mint(&donor, &500);
+    multisig.deposit(
+        &donor,
+        &token3.address,
+        &500,
+        &String::from_str(&env, "Airdrop"),
+    );
+    assert_eq!(
+        multisig.query_tracked_tokens(),
+        vec![&env, token1.address.clone(), token2.address.clone()]
+    );
+    assert_eq!(
+        multisig.query_treasury(&vec![&env, token3.address.clone()]),
+        vec![
+            &env,
+            TreasuryBalance {
+                token: token3.address.clone(),
+                balance: 500,
+                reserved: 0,
+                available: 500,
+                total_inflow: 0,
+                total_outflow: 0,
+            },
+        ]
+    );
+
+    update_config(
+        &multisig,
+        &vec![&env, member1.clone()],
+        ConfigUpdate::UntrackToken(token1.address.clone()),
+    );
+    assert_eq!(
+        multisig.query_tracked_tokens(),
+        vec![&env, token2.address.clone()]
+    );
+}
+
+#[test]
+fn deposit_amount_has_to_be_positive() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        vec![&env, member1.clone()],
+        None,
+    );
+    let token = deploy_token_contract(&env, &member1);
+
+    assert_eq!(
+        multisig.try_deposit(&member1, &token.address, &0, &String::from_str(&env, "")),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    assert_eq!(
+        multisig.try_deposit(&member1, &token.address, &-1, &String::from_str(&env, "")),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+}
+
+#[test]
+fn deposit_records_depositor_and_memo() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let member1 = Address::generate(&env);
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        vec![&env, member1.clone()],
+        None,
+    );
+
+    let payer = Address::generate(&env);
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&payer, &1_000);
+
+    let memo = String::from_str(&env, "Membership dues, invoice 2024-017");
+    let deposit_id = multisig.deposit(&payer, &token.address, &250, &memo);
+
+    let expected_deposit = Deposit {
+        id: 1,
+        from: payer.clone(),
+        token: token.address.clone(),
+        amount: 250,
+        memo,
+        timestamp: 1_000,
+    };
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                multisig.address.clone(),
+                (
+                    String::from_str(&env, "Multisig"),
+                    String::from_str(&env, "Deposit")
+                )
+                    .into_val(&env),
+                expected_deposit.clone().into_val(&env),
+            )
+        ]
+    );
+    assert_eq!(deposit_id, 1);
+    assert_eq!(multisig.query_last_deposit_id(), 1);
+    assert_eq!(multisig.query_deposit(&deposit_id), expected_deposit);
+    assert_eq!(token.balance(&multisig.address), 250);
+
+    assert_eq!(
+        multisig.try_query_deposit(&2),
+        Err(Ok(ContractError::DepositNotFound))
+    );
+    assert_eq!(
+        multisig.try_deposit(
+            &payer,
+            &token.address,
+            &250,
+            &String::from_bytes(&env, &[0u8; 257])
+        ),
+        Err(Ok(ContractError::DescriptionTooLong))
+    );
+}
+
+#[test]
+fn open_proposals_reserve_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let create_proposal = |amount: i128, expiration_date: Option<u64>| {
+        multisig.try_create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle#01"),
+            &String::from_str(&env, "TxTestDescription"),
+            &Address::generate(&env),
+            &amount,
+            &token.address,
+            &expiration_date,
+        )
+    };
+
+    create_proposal(6_000, Some(DAY_AS_TIMESTAMP))
+        .unwrap()
+        .unwrap();
+    create_proposal(3_000, None).unwrap().unwrap();
+    assert_eq!(
+        multisig.query_treasury(&vec![&env, token.address.clone()]),
+        vec![
+            &env,
+            TreasuryBalance {
+                token: token.address.clone(),
+                balance: 10_000,
+                reserved: 9_000,
+                available: 1_000,
+                total_inflow: 0,
+                total_outflow: 0,
+            },
+        ]
+    );
+
+    // the balance covers it, but most of it is already promised
+    assert_eq!(
+        create_proposal(2_000, None),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+
+    // expired proposals don't reserve funds anymore
+    env.ledger()
+        .with_mut(|li| li.timestamp = DAY_AS_TIMESTAMP + 1);
+    create_proposal(2_000, None).unwrap().unwrap();
+    assert_eq!(
+        multisig
+            .query_treasury(&vec![&env, token.address.clone()])
+            .get(0)
+            .unwrap()
+            .reserved,
+        5_000
+    );
+
+    // funds left the treasury after the proposal was created
+    token.burn(&multisig.address, &8_000);
+    multisig.sign_proposal(&member1, &2);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &2),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    multisig.sign_proposal(&member1, &3);
+    multisig.execute_proposal(&member1, &3);
+    assert_eq!(
+        multisig
+            .query_treasury(&vec![&env, token.address.clone()])
+            .get(0)
+            .unwrap(),
+        TreasuryBalance {
+            token: token.address.clone(),
+            balance: 0,
+            reserved: 3_000,
+            available: -3_000,
+            total_inflow: 0,
+            total_outflow: 0,
+        }
+    );
+
+    // withdrawn proposals release their funds
+    multisig.cancel_proposal(&member1, &2);
+    assert_eq!(
+        multisig
+            .query_treasury(&vec![&env, token.address.clone()])
+            .get(0)
+            .unwrap()
+            .reserved,
+        0
+    );
+}
@@ -2,17 +2,19 @@ extern crate std;
 
 use soroban_sdk::{
     testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger},
-    vec, Address, Env, IntoVal, String, Symbol,
+    vec, Address, BytesN, Env, IntoVal, InvokeError, String, Symbol,
 };
 
 use crate::{
+    contract::MultisigClient,
     error::ContractError,
     tests::setup::{DAY_AS_TIMESTAMP, TWO_WEEKS_EXPIRATION_DATE},
+    STORAGE_VERSION,
 };
 
-use super::setup::initialize_multisig_contract;
+use super::setup::{deploy_token_contract, initialize_multisig_contract};
 
-mod utils {
+pub mod utils {
     use soroban_sdk::{BytesN, Env};
 
     #[allow(clippy::too_many_arguments)]
@@ -22,9 +24,27 @@ mod utils {
         );
     }
 
+    // Release before storage versioning, signatures were stored as `()` and
+    // transaction amounts as `u64`
+    #[allow(clippy::too_many_arguments)]
+    pub mod legacy_multisig {
+        soroban_sdk::contractimport!(file = "fixtures/soroban_multisig_v0.wasm");
+    }
+
     pub fn multisig_wasm_hash(env: &Env) -> BytesN<32> {
         env.deployer().upload_contract_wasm(multisig::WASM)
     }
+
+    // Same code with a custom section tagged by `release`, so every release
+    // gets its own wasm hash
+    pub fn multisig_release_wasm_hash(env: &Env, release: u8) -> BytesN<32> {
+        let name = b"release";
+        let mut wasm = super::std::vec::Vec::from(multisig::WASM);
+        wasm.extend_from_slice(&[0, name.len() as u8 + 2, name.len() as u8]);
+        wasm.extend_from_slice(name);
+        wasm.push(release);
+        env.deployer().upload_contract_wasm(wasm.as_slice())
+    }
 }
 
 #[test]
@@ -355,3 +375,242 @@ fn query_proposal_readiness() {
     multisig.sign_proposal(&member4, &proposal_id);
     assert!(multisig.is_proposal_ready(&proposal_id));
 }
+
+#[test]
+fn upgrade_from_release_before_storage_versioning_migrates_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    // deployment running the release before storage versioning
+    let legacy_multisig = utils::legacy_multisig::Client::new(
+        &env,
+        &env.register_contract_wasm(None, utils::legacy_multisig::WASM),
+    );
+    legacy_multisig.initialize(
+        &String::from_str(&env, "MultisigName"),
+        &String::from_str(&env, "Example description of this multisig"),
+        &members,
+        &None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&legacy_multisig.address, &30_000);
+    let recipient = Address::generate(&env);
+    for _ in 0..3 {
+        legacy_multisig.create_transaction_proposal(
+            &member1,
+            &String::from_str(&env, "TxTitle"),
+            &String::from_str(&env, "TxTestDescription"),
+            &recipient,
+            &10_000,
+            &token.address,
+            &None,
+        );
+    }
+    legacy_multisig.sign_proposal(&member1, &1);
+
+    // the only upgrade path of the old release is a plain update proposal
+    legacy_multisig.create_update_proposal(
+        &member1,
+        &String::from_str(&env, "upgrade"),
+        &String::from_str(&env, "description"),
+        &utils::multisig_wasm_hash(&env),
+        &None,
+    );
+    legacy_multisig.sign_proposal(&member1, &4);
+    legacy_multisig.sign_proposal(&member2, &4);
+    legacy_multisig.execute_proposal(&member1, &4);
+
+    let multisig = MultisigClient::new(&env, &legacy_multisig.address);
+    assert_eq!(multisig.query_storage_version(), 0);
+    assert!(multisig.try_query_proposal(&1).is_err());
+
+    assert_eq!(
+        multisig.try_migrate(&Address::generate(&env), &0, &2),
+        Err(Ok(ContractError::UnauthorizedNotAMember))
+    );
+    assert_eq!(
+        multisig.try_migrate(&member1, &1, &2),
+        Err(Ok(ContractError::InvalidMigrationVersion))
+    );
+
+    // the migration is split over several calls
+    assert!(!multisig.migrate(&member1, &0, &2));
+    assert_eq!(multisig.query_storage_version(), 0);
+    assert!(multisig.migrate(&member2, &0, &2));
+    assert_eq!(multisig.query_storage_version(), STORAGE_VERSION);
+
+    // migration runs only once
+    assert_eq!(
+        multisig.try_migrate(&member1, &STORAGE_VERSION, &2),
+        Err(Ok(ContractError::MigrationNotScheduled))
+    );
+
+    let proposal = multisig.query_proposal(&1);
+    assert_eq!(proposal.stages, vec![&env]);
+    assert_eq!(proposal.current_stage, 0);
+    assert_eq!(proposal.not_before, None);
+    assert_eq!(multisig.query_all_proposals().len(), 4);
+    assert_eq!(
+        multisig.query_signatures(&1),
+        vec![
+            &env,
+            (member1.clone(), true, None),
+            (member2.clone(), false, None)
+        ]
+    );
+
+    // the open transactions reserve their amounts
+    let reserved = |multisig: &MultisigClient| {
+        multisig
+            .query_treasury(&vec![&env, token.address.clone()])
+            .get(0)
+            .unwrap()
+            .reserved
+    };
+    assert_eq!(reserved(&multisig), 30_000);
+
+    multisig.sign_proposal(&member2, &1);
+    multisig.execute_proposal(&member1, &1);
+    assert_eq!(token.balance(&recipient), 10_000i128);
+    assert_eq!(reserved(&multisig), 20_000);
+}
+
+#[test]
+fn create_migration_proposal_validates_storage_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+    assert_eq!(multisig.query_storage_version(), STORAGE_VERSION);
+
+    // storage of a fresh deployment is already up to date
+    assert_eq!(
+        multisig.try_migrate(&member1, &STORAGE_VERSION, &10),
+        Err(Ok(ContractError::MigrationNotScheduled))
+    );
+
+    let new_wasm_hash = utils::multisig_wasm_hash(&env);
+    assert_eq!(
+        multisig.try_create_migration_proposal(
+            &member1,
+            &String::from_str(&env, "upgrade"),
+            &String::from_str(&env, "description"),
+            &new_wasm_hash,
+            &0,
+            &None,
+        ),
+        Err(Ok(ContractError::InvalidMigrationVersion))
+    );
+    multisig.create_migration_proposal(
+        &member1,
+        &String::from_str(&env, "upgrade"),
+        &String::from_str(&env, "description"),
+        &new_wasm_hash,
+        &STORAGE_VERSION,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+    multisig.sign_proposal(&member1, &proposal_id);
+    multisig.execute_proposal(&member1, &proposal_id);
+
+    let upgrade_history = multisig.query_multisig_info().upgrade_history;
+    assert_eq!(upgrade_history.len(), 1);
+    assert!(upgrade_history.get(0).unwrap().with_migration);
+}
+
+#[test]
+fn create_update_proposal_validates_new_wasm() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    // wasm that was never uploaded can't be probed by the dry run nor installed on execution
+    let missing_wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    assert_eq!(
+        multisig.try_query_wasm_compatibility(&missing_wasm_hash),
+        Err(Err(InvokeError::Abort))
+    );
+    multisig.create_update_proposal(
+        &member1,
+        &String::from_str(&env, "update"),
+        &String::from_str(&env, "description"),
+        &missing_wasm_hash,
+        &None,
+    );
+    let missing_wasm_proposal_id = multisig.query_last_proposal_id();
+    multisig.sign_proposal(&member1, &missing_wasm_proposal_id);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &missing_wasm_proposal_id),
+        Err(Err(InvokeError::Abort))
+    );
+
+    // the dry run shows that uploaded code which isn't a multisig lacks its entrypoints
+    let token_wasm_hash = env
+        .deployer()
+        .upload_contract_wasm(crate::token_contract::WASM);
+    assert_eq!(
+        multisig.try_query_wasm_compatibility(&token_wasm_hash),
+        Err(Ok(ContractError::IncompatibleWasm))
+    );
+
+    let new_wasm_hash = utils::multisig_wasm_hash(&env);
+    assert_eq!(
+        multisig.try_create_update_proposal(
+            &member1,
+            &String::from_bytes(&env, &[0u8; 65]),
+            &String::from_str(&env, "description"),
+            &new_wasm_hash,
+            &None,
+        ),
+        Err(Ok(ContractError::TitleTooLong))
+    );
+    assert_eq!(
+        multisig.try_create_update_proposal(
+            &member1,
+            &String::from_str(&env, "update"),
+            &String::from_bytes(&env, &[0u8; 257]),
+            &new_wasm_hash,
+            &None,
+        ),
+        Err(Ok(ContractError::DescriptionTooLong))
+    );
+
+    // the same wasm can be proposed more than once
+    for _ in 0..2 {
+        multisig.create_update_proposal(
+            &member1,
+            &String::from_str(&env, "update"),
+            &String::from_str(&env, "description"),
+            &new_wasm_hash,
+            &None,
+        );
+    }
+    assert_eq!(multisig.query_last_proposal_id(), 3);
+}
@@ -0,0 +1,166 @@
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, vec, Address, BytesN, Env, String,
+};
+
+use super::{
+    setup::{initialize_multisig_contract, sign_and_execute_proposal},
+    update_proposal::utils,
+};
+use crate::{error::ContractError, storage::ConfigUpdate};
+
+// Stand-in for the deployer's registry of audited releases
+#[contract]
+pub struct MockUpgradeRegistry;
+
+#[contractimpl]
+impl MockUpgradeRegistry {
+    pub fn publish_wasm_hash(env: Env, wasm_hash: BytesN<32>, audit_note: String) {
+        env.storage().persistent().set(&wasm_hash, &audit_note);
+    }
+
+    pub fn revoke_wasm_hash(env: Env, wasm_hash: BytesN<32>) {
+        env.storage().persistent().remove(&wasm_hash);
+    }
+
+    pub fn query_published_wasm(env: Env, wasm_hash: BytesN<32>) -> Option<String> {
+        env.storage().persistent().get(&wasm_hash)
+    }
+}
+
+#[test]
+fn upgrades_are_limited_to_published_wasm_hashes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = vec![&env, member1.clone(), member2.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let registry =
+        MockUpgradeRegistryClient::new(&env, &env.register_contract(None, MockUpgradeRegistry {}));
+    assert_eq!(multisig.query_upgrade_registry(), None);
+
+    multisig.create_config_proposal(
+        &member1,
+        &String::from_str(&env, "Upgrade registry"),
+        &String::from_str(&env, "Only allow audited releases"),
+        &ConfigUpdate::UpgradeRegistry(registry.address.clone()),
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(
+        multisig.query_upgrade_registry(),
+        Some(registry.address.clone())
+    );
+
+    let new_wasm_hash = utils::multisig_wasm_hash(&env);
+    assert_eq!(
+        multisig.try_create_update_proposal(
+            &member1,
+            &String::from_str(&env, "update"),
+            &String::from_str(&env, "description"),
+            &new_wasm_hash,
+            &None,
+        ),
+        Err(Ok(ContractError::WasmHashNotApproved))
+    );
+    assert_eq!(
+        multisig.try_create_migration_proposal(
+            &member1,
+            &String::from_str(&env, "update"),
+            &String::from_str(&env, "description"),
+            &new_wasm_hash,
+            &multisig.query_storage_version(),
+            &None,
+        ),
+        Err(Ok(ContractError::WasmHashNotApproved))
+    );
+
+    registry.publish_wasm_hash(
+        &new_wasm_hash,
+        &String::from_str(&env, "v1.1.0, audit report #42"),
+    );
+    multisig.create_update_proposal(
+        &member1,
+        &String::from_str(&env, "update"),
+        &String::from_str(&env, "description"),
+        &new_wasm_hash,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(multisig.query_multisig_info().version_proposal, 1);
+}
+
+#[test]
+fn revoked_wasm_hash_cannot_be_executed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let registry =
+        MockUpgradeRegistryClient::new(&env, &env.register_contract(None, MockUpgradeRegistry {}));
+    multisig.create_config_proposal(
+        &member1,
+        &String::from_str(&env, "Upgrade registry"),
+        &String::from_str(&env, "Only allow audited releases"),
+        &ConfigUpdate::UpgradeRegistry(registry.address.clone()),
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+
+    let new_wasm_hash = utils::multisig_wasm_hash(&env);
+    registry.publish_wasm_hash(
+        &new_wasm_hash,
+        &String::from_str(&env, "v1.1.0, audit report #42"),
+    );
+    multisig.create_update_proposal(
+        &member1,
+        &String::from_str(&env, "update"),
+        &String::from_str(&env, "description"),
+        &new_wasm_hash,
+        &None,
+    );
+    let proposal_id = multisig.query_last_proposal_id();
+    multisig.sign_proposal(&member1, &proposal_id);
+
+    // a vulnerability was found in the release before the proposal got executed
+    registry.revoke_wasm_hash(&new_wasm_hash);
+    assert_eq!(
+        multisig.try_execute_proposal(&member1, &proposal_id),
+        Err(Ok(ContractError::WasmHashNotApproved))
+    );
+
+    // removing the registry lifts the restriction
+    multisig.create_config_proposal(
+        &member1,
+        &String::from_str(&env, "Upgrade registry"),
+        &String::from_str(&env, "Allow any release"),
+        &ConfigUpdate::RemoveUpgradeRegistry,
+        &None,
+    );
+    sign_and_execute_proposal(&multisig, &members, multisig.query_last_proposal_id());
+    assert_eq!(multisig.query_upgrade_registry(), None);
+
+    multisig.execute_proposal(&member1, &proposal_id);
+    assert_eq!(multisig.query_multisig_info().version_proposal, 1);
+}
@@ -0,0 +1,237 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+use super::setup::{
+    deploy_token_contract, initialize_multisig_contract, update_vesting, DAY_AS_TIMESTAMP,
+};
+use crate::{
+    error::ContractError,
+    storage::{TreasuryBalance, Vesting, VestingAction, VestingTerms},
+};
+
+const YEAR_AS_TIMESTAMP: u64 = DAY_AS_TIMESTAMP * 365;
+
+#[test]
+fn beneficiary_claims_after_cliff() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &50_000);
+
+    let beneficiary = Address::generate(&env);
+    let terms = VestingTerms {
+        beneficiary: beneficiary.clone(),
+        token: token.address.clone(),
+        amount: 40_000,
+        start_timestamp: 0,
+        cliff_timestamp: YEAR_AS_TIMESTAMP,
+        end_timestamp: YEAR_AS_TIMESTAMP * 4,
+    };
+    update_vesting(&multisig, &members, VestingAction::Create(terms));
+    assert_eq!(multisig.query_last_vesting_id(), 1);
+    assert_eq!(
+        multisig.query_vesting(&1),
+        Vesting {
+            id: 1,
+            beneficiary: beneficiary.clone(),
+            token: token.address.clone(),
+            amount: 40_000,
+            start_timestamp: 0,
+            cliff_timestamp: YEAR_AS_TIMESTAMP,
+            end_timestamp: YEAR_AS_TIMESTAMP * 4,
+            claimed: 0,
+            revoked_timestamp: None,
+        }
+    );
+    assert_eq!(
+        multisig
+            .query_treasury(&vec![&env, token.address.clone()])
+            .get(0)
+            .unwrap()
+            .available,
+        10_000
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = YEAR_AS_TIMESTAMP - 1);
+    assert_eq!(
+        multisig.try_claim_vested(&beneficiary, &1),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    assert_eq!(
+        multisig.try_claim_vested(&member1, &1),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    // the first year unlocks at the cliff
+    env.ledger().with_mut(|li| li.timestamp = YEAR_AS_TIMESTAMP);
+    assert_eq!(multisig.claim_vested(&beneficiary, &1), 10_000);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = YEAR_AS_TIMESTAMP * 5 / 2);
+    assert_eq!(multisig.claim_vested(&beneficiary, &1), 15_000);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = YEAR_AS_TIMESTAMP * 5);
+    assert_eq!(multisig.claim_vested(&beneficiary, &1), 15_000);
+    assert_eq!(token.balance(&beneficiary), 40_000);
+    assert_eq!(
+        multisig.try_claim_vested(&beneficiary, &1),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+}
+
+#[test]
+fn revoked_vesting_returns_unvested_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let members = vec![&env, member1.clone()];
+
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        members.clone(),
+        None,
+    );
+
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &40_000);
+
+    let beneficiary = Address::generate(&env);
+    update_vesting(
+        &multisig,
+        &members,
+        VestingAction::Create(VestingTerms {
+            beneficiary: beneficiary.clone(),
+            token: token.address.clone(),
+            amount: 40_000,
+            start_timestamp: 0,
+            cliff_timestamp: YEAR_AS_TIMESTAMP,
+            end_timestamp: YEAR_AS_TIMESTAMP * 4,
+        }),
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = YEAR_AS_TIMESTAMP * 2);
+    update_vesting(&multisig, &members, VestingAction::Revoke(1));
+    assert_eq!(
+        multisig.query_vesting(&1).revoked_timestamp,
+        Some(YEAR_AS_TIMESTAMP * 2)
+    );
+    assert_eq!(
+        multisig.try_create_vesting_proposal(
+            &member1,
+            &String::from_str(&env, "Vesting"),
+            &String::from_str(&env, "Revoke again"),
+            &VestingAction::Revoke(1),
+            &None,
+        ),
+        Err(Ok(ContractError::InvalidVesting))
+    );
+
+    // the vested part can still be claimed, the rest is available to the treasury
+    env.ledger()
+        .with_mut(|li| li.timestamp = YEAR_AS_TIMESTAMP * 3);
+    assert_eq!(
+        multisig.query_treasury(&vec![&env, token.address.clone()]),
+        vec![
+            &env,
+            TreasuryBalance {
+                token: token.address.clone(),
+                balance: 40_000,
+                reserved: 20_000,
+                available: 20_000,
+                total_inflow: 0,
+                total_outflow: 0,
+            }
+        ]
+    );
+    assert_eq!(multisig.claim_vested(&beneficiary, &1), 20_000);
+    assert_eq!(
+        multisig.try_claim_vested(&beneficiary, &1),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+}
+
+#[test]
+fn invalid_vesting_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member1 = Address::generate(&env);
+    let multisig = initialize_multisig_contract(
+        &env,
+        String::from_str(&env, "MultisigName"),
+        String::from_str(&env, "Example description of this multisig"),
+        vec![&env, member1.clone()],
+        None,
+    );
+    let token = deploy_token_contract(&env, &member1);
+    token.mint(&multisig.address, &10_000);
+
+    let create_vesting = |action: VestingAction| {
+        multisig.try_create_vesting_proposal(
+            &member1,
+            &String::from_str(&env, "Vesting"),
+            &String::from_str(&env, "Team allocation of the new contributor"),
+            &action,
+            &None,
+        )
+    };
+    let terms = VestingTerms {
+        beneficiary: Address::generate(&env),
+        token: token.address.clone(),
+        amount: 10_000,
+        start_timestamp: 0,
+        cliff_timestamp: YEAR_AS_TIMESTAMP,
+        end_timestamp: YEAR_AS_TIMESTAMP * 4,
+    };
+
+    for terms in [
+        VestingTerms {
+            amount: 0,
+            ..terms.clone()
+        },
+        VestingTerms {
+            cliff_timestamp: YEAR_AS_TIMESTAMP * 5,
+            ..terms.clone()
+        },
+        VestingTerms {
+            start_timestamp: YEAR_AS_TIMESTAMP * 2,
+            ..terms.clone()
+        },
+    ] {
+        assert_eq!(
+            create_vesting(VestingAction::Create(terms)),
+            Err(Ok(ContractError::InvalidVesting))
+        );
+    }
+    assert_eq!(
+        create_vesting(VestingAction::Create(VestingTerms {
+            amount: 10_001,
+            ..terms
+        })),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    assert_eq!(
+        create_vesting(VestingAction::Revoke(1)),
+        Err(Ok(ContractError::VestingNotFound))
+    );
+}